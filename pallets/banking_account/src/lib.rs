@@ -1,14 +1,26 @@
 use frame_support::{
     pallet_prelude::*,
+    storage::{self, TransactionOutcome},
     traits::{Currency, ExistenceRequirement, ReservableCurrency},
     weights::Weight,
 };
 use frame_system::pallet_prelude::*;
-use sp_runtime::traits::{AtLeast32BitUnsigned};
+use sp_runtime::{ArithmeticError, ModuleError, Permill, TokenError};
+use sp_runtime::traits::{
+    AtLeast32BitUnsigned, CheckedAdd, DispatchInfoOf, PostDispatchInfoOf, Saturating, SignedExtension,
+    UniqueSaturatedFrom, UniqueSaturatedInto, Zero,
+};
+use sp_core::sr25519;
 use sp_std::vec::Vec;
-use codec::{Encode, Decode};
+use codec::{Codec, Encode, Decode};
 use scale_info::TypeInfo;
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
 pub enum Status {
     Operative,
@@ -18,7 +30,169 @@ pub enum Status {
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
-pub struct BankingAccount<AccountId, Balance, Moment> {
+pub enum RiskFlag {
+    Pep,
+    HighRisk,
+    Exempt,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum NotificationKind {
+    OnDeposit,
+    OnWithdraw,
+    OnLowBalance,
+}
+
+impl NotificationKind {
+    /// Bit for this kind in an account's `notify_flags`.
+    pub fn flag(&self) -> u8 {
+        match self {
+            NotificationKind::OnDeposit => 1 << 0,
+            NotificationKind::OnWithdraw => 1 << 1,
+            NotificationKind::OnLowBalance => 1 << 2,
+        }
+    }
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum LimitPeriod {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum CapFrequency {
+    Monthly,
+    Quarterly,
+    Annually,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum TxnKind {
+    Deposit,
+    Withdrawal,
+    TransferIn,
+    TransferOut,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct TxnRecord<AccountId, Balance, Moment> {
+    pub kind: TxnKind,
+    pub amount: Balance,
+    pub counterparty: Option<AccountId>,
+    pub at: Moment,
+}
+
+// Wallet-facing snapshot of an account; identity documents are masked
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct AccountExport<AccountId, Balance, Moment> {
+    pub account_number: Vec<u8>,
+    pub ifsc_code: Vec<u8>,
+    pub bank_name: Vec<u8>,
+    pub branch_name: Vec<u8>,
+    pub account_holder: AccountId,
+    pub holder_pan: Option<Vec<u8>>,
+    pub holder_aadhaar: Option<Vec<u8>>,
+    pub account_type: Vec<u8>,
+    pub opening_date: Moment,
+    pub status: Status,
+    pub current_balance: Balance,
+    pub overdraft_used: Balance,
+    pub parent_account: Option<AccountId>,
+    pub child_accounts: Vec<AccountId>,
+    pub recent_transactions: Vec<TxnRecord<AccountId, Balance, Moment>>,
+}
+
+// Balance and drawn overdraft an account record was loaded with, so writing it back moves
+// the running totals by the change alone
+#[derive(Clone, Copy, Default)]
+struct Position<Balance> {
+    balance: Balance,
+    overdraft: Balance,
+}
+
+// Bank-wide figures for regulators, maintained incrementally
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct BankStatistics<Balance> {
+    pub total_accounts: u32,
+    pub total_deposits: Balance,
+    pub operative: u32,
+    pub dormant: u32,
+    pub closed: u32,
+    pub frozen: u32,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum BankingTask<AccountId> {
+    // Accrue interest on the account after the given one (or the first account), then
+    // queue the next step of the sweep
+    SweepInterest(Option<AccountId>),
+    // Drop the pair transfer record after the given pair (or the first) if its window has
+    // lapsed, then queue the next step
+    PrunePairTransfers(Option<(AccountId, AccountId)>),
+    // Release the expired earmarks of the account after the given one (or the first
+    // account with expiries), then queue the next step
+    SweepExpiredLedgers(Option<AccountId>),
+    // Warn or mark dormant the account after the given one (or the first), then queue
+    // the next step
+    SweepDormancy(Option<AccountId>),
+}
+
+// The recurring sweeps, of which at most one of each may be queued at a time
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum SweepKind {
+    Interest,
+    PairTransfers,
+    ExpiredLedgers,
+    Dormancy,
+}
+
+impl<AccountId> BankingTask<AccountId> {
+    pub fn kind(&self) -> SweepKind {
+        match self {
+            BankingTask::SweepInterest(_) => SweepKind::Interest,
+            BankingTask::PrunePairTransfers(_) => SweepKind::PairTransfers,
+            BankingTask::SweepExpiredLedgers(_) => SweepKind::ExpiredLedgers,
+            BankingTask::SweepDormancy(_) => SweepKind::Dormancy,
+        }
+    }
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum DenominationPolicy {
+    Reject,
+    RoundDown,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum AccountTemplate {
+    BasicSavings,
+    Current,
+    SalaryAccount,
+}
+
+impl AccountTemplate {
+    pub fn account_type(&self) -> Vec<u8> {
+        match self {
+            AccountTemplate::BasicSavings => b"SAVINGS".to_vec(),
+            AccountTemplate::Current => b"CURRENT".to_vec(),
+            AccountTemplate::SalaryAccount => b"SALARY".to_vec(),
+        }
+    }
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxFlags, MaxLedgers, MaxLabel, MaxWhitelist))]
+pub struct BankingAccount<
+    AccountId,
+    Balance,
+    Moment,
+    MaxFlags: Get<u32>,
+    MaxLedgers: Get<u32>,
+    MaxLabel: Get<u32>,
+    MaxWhitelist: Get<u32>,
+> {
     pub account_number: Vec<u8>,
     pub ifsc_code: Vec<u8>,
     pub micr_code: Option<Vec<u8>>,
@@ -34,19 +208,83 @@ pub struct BankingAccount<AccountId, Balance, Moment> {
     pub opening_date: Moment,
     pub status: Status,
     pub current_balance: Balance,
+    pub minimum_balance: Balance,
     pub overdraft_limit: Option<Balance>,
+    pub overdraft_used: Balance,
+    // Share of the overdraft limit whose crossing raises `OverdraftUtilizationHigh`
+    pub overdraft_alert: Option<Permill>,
+
+    // Interest
+    pub capitalization: CapFrequency,
+    pub accrued_interest: Balance,
+    pub last_accrual: Moment,
+    pub last_capitalization: Moment,
+    // Keep earning while Frozen; otherwise frozen time earns nothing
+    pub accrue_while_frozen: bool,
+
     pub has_cheque_book: bool,
     pub has_atm_debit_card: bool,
     pub has_internet_banking: bool,
     pub has_mobile_banking: bool,
     pub last_txn: Option<Moment>,
+    pub last_heartbeat: Option<Moment>,
+
+    // Succession
+    pub nominee: Option<AccountId>,
 
     // Hierarchy
     pub parent_account: Option<AccountId>,
     pub child_accounts: Vec<AccountId>,
+
+    // Regulatory reporting
+    pub risk_flags: BoundedVec<RiskFlag, MaxFlags>,
+    pub high_limit_override: bool,
+
+    // Fraud monitoring
+    pub velocity_anomaly: bool,
+    // Accept credits while every outbound flow is refused (e.g. during a dispute)
+    pub inbound_only: bool,
+
+    // Wallet notifications: `NotificationKind` bits, and the balance below which
+    // `OnLowBalance` fires
+    pub notify_flags: u8,
+    pub low_balance_threshold: Balance,
+
+    // Holder-chosen outflow cap per period, enforced alongside the risk-tiered daily limit
+    pub spending_limit: Option<(LimitPeriod, Balance)>,
+
+    // Secondary auth: blake2-256 of a holder secret required on outbound transfers,
+    // and wrong preimages since the last success
+    pub auth_hash: Option<[u8; 32]>,
+    pub failed_auth_attempts: u32,
+
+    // Earmarked buckets carved out of current_balance
+    pub sub_ledgers: BoundedVec<(BoundedVec<u8, MaxLabel>, Balance), MaxLedgers>,
+
+    // Outbound destination whitelist
+    pub whitelist_enabled: bool,
+    pub whitelist: BoundedVec<AccountId, MaxWhitelist>,
 }
 
 type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+type BankingAccountOf<T> = BankingAccount<
+    <T as frame_system::Config>::AccountId,
+    BalanceOf<T>,
+    <T as Config>::Moment,
+    <T as Config>::MaxFlags,
+    <T as Config>::MaxLedgers,
+    <T as Config>::MaxLabel,
+    <T as Config>::MaxWhitelist,
+>;
+type MetadataOf<T> = BoundedVec<
+    (BoundedVec<u8, <T as Config>::MaxMetadataKey>, BoundedVec<u8, <T as Config>::MaxMetadataValue>),
+    <T as Config>::MaxMetadataEntries,
+>;
+type TxnRecordOf<T> = TxnRecord<<T as frame_system::Config>::AccountId, BalanceOf<T>, <T as Config>::Moment>;
+type AccountExportOf<T> = AccountExport<<T as frame_system::Config>::AccountId, BalanceOf<T>, <T as Config>::Moment>;
+
+/// Characters of an identity number left visible when it is masked.
+const UNMASKED_SUFFIX: usize = 4;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -58,6 +296,45 @@ pub mod pallet {
         type Currency: ReservableCurrency<Self::AccountId>;
         type Moment: AtLeast32BitUnsigned + Parameter + Default + Copy + MaybeSerializeDeserialize + MaxEncodedLen;
         type WeightInfo: WeightInfo;
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        type MaxFlags: Get<u32>;
+        type DailyLimit: Get<BalanceOf<Self>>;
+        type HighRiskDailyLimit: Get<BalanceOf<Self>>;
+        type MinimumBalance: Get<BalanceOf<Self>>;
+        type CurrentMinimumBalance: Get<BalanceOf<Self>>;
+        type CurrentOverdraftLimit: Get<BalanceOf<Self>>;
+        type VelocityWindow: Get<Self::Moment>;
+        type VelocityThreshold: Get<u32>;
+        type FreezeOnVelocityAnomaly: Get<bool>;
+        type MaxLedgers: Get<u32>;
+        type MaxLabel: Get<u32>;
+        type ReconciliationTolerance: Get<BalanceOf<Self>>;
+        type MinDenomination: Get<BalanceOf<Self>>;
+        type DenominationPolicy: Get<DenominationPolicy>;
+        type MaxWhitelist: Get<u32>;
+        type MaxSingleTxn: Get<BalanceOf<Self>>;
+        type InterestRate: Get<Permill>;
+        type BlocksPerYear: Get<Self::Moment>;
+        type Treasury: Get<Self::AccountId>;
+        type InactivityForSuccession: Get<Self::Moment>;
+        type SuccessionDelay: Get<Self::Moment>;
+        type InterestSweepInterval: Get<Self::Moment>;
+        type MaxRecentTxns: Get<u32>;
+        type PairTransferWindow: Get<Self::Moment>;
+        type MaxPairTransferPerWindow: Get<BalanceOf<Self>>;
+        type ClosureGracePeriod: Get<Self::Moment>;
+        type WelcomeCredit: Get<BalanceOf<Self>>;
+        type BankRegistry: BankRegistry;
+        type MaxHierarchyDepth: Get<u32>;
+        type BlocksPerDay: Get<Self::Moment>;
+        type MaxAuthAttempts: Get<u32>;
+        type MaxMetadataKey: Get<u32>;
+        type MaxMetadataValue: Get<u32>;
+        type MaxMetadataEntries: Get<u32>;
+        type LedgerExpirySweepInterval: Get<Self::Moment>;
+        type DormancyPeriod: Get<Self::Moment>;
+        type DormancyWarningPeriod: Get<Self::Moment>;
+        type DormancySweepInterval: Get<Self::Moment>;
     }
 
     #[pallet::pallet]
@@ -68,7 +345,54 @@ pub mod pallet {
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         AccountCreated(T::AccountId, BalanceOf<T>),
+        SubAccountProposed(T::AccountId, T::AccountId),
         SubAccountAdded(T::AccountId, T::AccountId),
+        RiskFlagAdded(T::AccountId, RiskFlag),
+        RiskFlagRemoved(T::AccountId, RiskFlag),
+        HierarchyConsolidated(T::AccountId, BalanceOf<T>),
+        Deposited(T::AccountId, BalanceOf<T>),
+        Withdrawn(T::AccountId, BalanceOf<T>),
+        Transferred(T::AccountId, T::AccountId, BalanceOf<T>),
+        VelocityAnomalyDetected(T::AccountId, u32),
+        VelocityAnomalyCleared(T::AccountId),
+        LedgerAllocated(T::AccountId, BoundedVec<u8, T::MaxLabel>, BalanceOf<T>),
+        LedgerWithdrawn(T::AccountId, BoundedVec<u8, T::MaxLabel>, BalanceOf<T>),
+        ReconciliationResult(BalanceOf<T>, BalanceOf<T>, bool),
+        SignedTransferExecuted(T::AccountId, T::AccountId, BalanceOf<T>, u64),
+        WhitelistEntryAdded(T::AccountId, T::AccountId),
+        WhitelistEntryRemoved(T::AccountId, T::AccountId),
+        WhitelistToggled(T::AccountId, bool),
+        HighLimitOverrideSet(T::AccountId, bool),
+        CapitalizationSet(T::AccountId, CapFrequency),
+        InterestCapitalized(T::AccountId, BalanceOf<T>),
+        Heartbeat(T::AccountId),
+        NomineeSet(T::AccountId, Option<T::AccountId>),
+        SuccessionInitiated(T::AccountId, T::AccountId, T::Moment),
+        SuccessionClaimed(T::AccountId, T::AccountId),
+        InboundOnlySet(T::AccountId),
+        InboundOnlyCleared(T::AccountId),
+        SubAccountDetached(T::AccountId, T::AccountId),
+        ClosureRequested(T::AccountId, T::Moment),
+        ClosureFinalized(T::AccountId, BalanceOf<T>),
+        ClosureCancelled(T::AccountId),
+        WelcomeCreditGranted(T::AccountId, BalanceOf<T>),
+        NotificationPreferencesSet(T::AccountId, u8, BalanceOf<T>),
+        AccountNotification(T::AccountId, NotificationKind),
+        AccountSeized(T::AccountId, T::AccountId, BalanceOf<T>),
+        SpendingLimitSet(T::AccountId, LimitPeriod, BalanceOf<T>),
+        AuthSecretSet(T::AccountId, bool),
+        AuthFailed(T::AccountId, u32),
+        AuthAttemptsReset(T::AccountId),
+        AccountMetadataSet(T::AccountId, BoundedVec<u8, T::MaxMetadataKey>),
+        AccountMetadataRemoved(T::AccountId, BoundedVec<u8, T::MaxMetadataKey>),
+        HoldExpired(T::AccountId, BoundedVec<u8, T::MaxLabel>, BalanceOf<T>),
+        OverdraftAlertSet(T::AccountId, Option<Permill>),
+        OverdraftUtilizationHigh(T::AccountId, Permill),
+        AccountClosureCertificate(T::AccountId, BalanceOf<T>, T::Moment, [u8; 32]),
+        AccrueWhileFrozenSet(T::AccountId, bool),
+        DormancyWarning(T::AccountId, T::Moment),
+        AccountDormant(T::AccountId),
+        AccountReactivated(T::AccountId),
     }
 
     #[pallet::error]
@@ -76,14 +400,228 @@ pub mod pallet {
         AccountAlreadyExists,
         AccountNotFound,
         CannotAddSelfAsChild,
+        RiskFlagAlreadySet,
+        RiskFlagNotSet,
+        TooManyRiskFlags,
+        NotAccountHolder,
+        AccountNotOperative,
+        InsufficientBalance,
+        CannotTransferToSelf,
+        LedgerNotFound,
+        LedgerOverAllocated,
+        LedgerInsufficientFunds,
+        TooManyLedgers,
+        LedgerDrift,
+        InvalidSignature,
+        InvalidNonce,
+        InvalidDenomination,
+        TransferFailed,
+        DestinationNotWhitelisted,
+        AlreadyWhitelisted,
+        WhitelistEntryNotFound,
+        TooManyWhitelistEntries,
+        TxnExceedsLimit,
+        NotNominee,
+        AccountStillActive,
+        SuccessionNotReady,
+        OutstandingOverdraft,
+        PairLimitExceeded,
+        OutboundBlocked,
+        NotParentAccount,
+        ClosureAlreadyRequested,
+        ClosureNotRequested,
+        ChildrenStillAttached,
+        InvalidBankIdentifiers,
+        HierarchyCycle,
+        AccountNotFrozen,
+        SpendingLimitExceeded,
+        BalanceOverflow,
+        AuthRequired,
+        AuthLocked,
+        TooManyMetadataEntries,
+        MetadataKeyNotFound,
+        AuthFailed,
+        NoPendingSubAccount,
+        AlreadyHasParent,
     }
 
     #[pallet::storage]
     #[pallet::getter(fn bank_accounts)]
     pub type BankAccounts<T: Config> = StorageMap<
-        _, Blake2_128Concat, T::AccountId, BankingAccount<T::AccountId, BalanceOf<T>, T::Moment>
+        _, Blake2_128Concat, T::AccountId, BankingAccountOf<T>
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn signature_nonce)]
+    pub type SignatureNonces<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn task)]
+    pub type TaskQueue<T: Config> = StorageMap<_, Twox64Concat, u64, BankingTask<T::AccountId>>;
+
+    // Court-ordered seizures: where the funds went, how much, and when
+    #[pallet::storage]
+    #[pallet::getter(fn seizure)]
+    pub type Seizures<T: Config> = StorageMap<
+        _, Blake2_128Concat, T::AccountId, (T::AccountId, BalanceOf<T>, T::Moment)
+    >;
+
+    // Closure receipts: when each account closed, the blake2-256 hash of its final state, and that
+    // state itself, so the hash can be recomputed however the live record changes afterwards
+    #[pallet::storage]
+    #[pallet::getter(fn closed_account)]
+    pub type ClosedAccounts<T: Config> = StorageMap<
+        _, Blake2_128Concat, T::AccountId, (T::Moment, [u8; 32], BankingAccountOf<T>)
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn total_accounts)]
+    pub type TotalAccounts<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    // Sum of every account's `current_balance`, kept in step by `save_account`
+    #[pallet::storage]
+    #[pallet::getter(fn total_deposits)]
+    pub type TotalDeposits<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    // Sum of every account's `overdraft_used`, kept in step by `save_account`
+    #[pallet::storage]
+    #[pallet::getter(fn total_overdraft)]
+    pub type TotalOverdraft<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn status_count)]
+    pub type StatusCounts<T: Config> = StorageMap<_, Twox64Concat, Status, u32, ValueQuery>;
+
+    // Next task to run; persisted so work left over in one block resumes in the next
+    #[pallet::storage]
+    #[pallet::getter(fn task_head)]
+    pub type TaskHead<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn task_tail)]
+    pub type TaskTail<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    // Sweeps with a step still queued; a new pass of the same kind waits until it finishes
+    #[pallet::storage]
+    #[pallet::getter(fn sweep_pending)]
+    pub type SweepsPending<T: Config> = StorageMap<_, Twox64Concat, SweepKind, bool, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pending_succession)]
+    pub type PendingSuccessions<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::Moment>;
+
+    // Sub-account links proposed by a parent and awaiting the child's acceptance, keyed
+    // (child, parent), with the funding the parent offered
+    #[pallet::storage]
+    #[pallet::getter(fn pending_sub_account)]
+    pub type PendingSubAccounts<T: Config> = StorageDoubleMap<
+        _, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, Option<BalanceOf<T>>
+    >;
+
+    // Accounts with a closure in progress and when it was requested
+    #[pallet::storage]
+    #[pallet::getter(fn pending_closure)]
+    pub type PendingClosures<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::Moment>;
+
+    // Holders already gifted the welcome credit, so reopening cannot farm it
+    #[pallet::storage]
+    #[pallet::getter(fn welcome_credited)]
+    pub type WelcomeCredited<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn recent_txns)]
+    pub type RecentTxns<T: Config> = StorageMap<
+        _, Blake2_128Concat, T::AccountId, BoundedVec<TxnRecordOf<T>, T::MaxRecentTxns>, ValueQuery
+    >;
+
+    // Amount sent from one account to another since the start of the current pair window
+    #[pallet::storage]
+    #[pallet::getter(fn pair_transfers)]
+    pub type PairTransfers<T: Config> = StorageDoubleMap<
+        _, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, (T::Moment, BalanceOf<T>)
+    >;
+
+    // When each earmark lapses and its funds return to the spendable balance
+    #[pallet::storage]
+    #[pallet::getter(fn ledger_expiries)]
+    pub type LedgerExpiries<T: Config> = StorageMap<
+        _, Blake2_128Concat, T::AccountId, BoundedVec<(BoundedVec<u8, T::MaxLabel>, T::Moment), T::MaxLedgers>,
+        ValueQuery
+    >;
+
+    // Dormancy date each holder was last warned about, so a warning is sent once per date
+    #[pallet::storage]
+    #[pallet::getter(fn dormancy_warned)]
+    pub type DormancyWarned<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::Moment>;
+
+    // Small dApp-defined key/value pairs per account, keys unique
+    #[pallet::storage]
+    #[pallet::getter(fn account_metadata)]
+    pub type AccountMetadata<T: Config> = StorageMap<
+        _, Blake2_128Concat, T::AccountId, MetadataOf<T>, ValueQuery
+    >;
+
+    // Outflow in the account's current limit period, keyed by the period index
+    #[pallet::storage]
+    #[pallet::getter(fn period_outflow)]
+    pub type PeriodOutflow<T: Config> = StorageMap<
+        _, Blake2_128Concat, T::AccountId, (T::Moment, BalanceOf<T>)
+    >;
+
+    // Outflow counted against the risk-tiered daily limit, keyed by the day index
+    #[pallet::storage]
+    pub type DailyOutflow<T: Config> = StorageMap<
+        _, Blake2_128Concat, T::AccountId, (T::Moment, BalanceOf<T>)
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn txn_velocity)]
+    pub type TxnVelocity<T: Config> = StorageMap<
+        _, Blake2_128Concat, T::AccountId, (T::Moment, u32)
     >;
 
+    // Status an account held before a velocity anomaly froze it
+    #[pallet::storage]
+    pub type VelocityFrozenFrom<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Status>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            let now = Self::now();
+            let mut weight = Weight::zero();
+
+            let interval = T::InterestSweepInterval::get();
+            if !interval.is_zero() && (now % interval).is_zero() {
+                Self::start_sweep(BankingTask::SweepInterest(None));
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 3));
+            }
+
+            let window = T::PairTransferWindow::get();
+            if !window.is_zero() && (now % window).is_zero() {
+                Self::start_sweep(BankingTask::PrunePairTransfers(None));
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 3));
+            }
+
+            let expiry_interval = T::LedgerExpirySweepInterval::get();
+            if !expiry_interval.is_zero() && (now % expiry_interval).is_zero() {
+                Self::start_sweep(BankingTask::SweepExpiredLedgers(None));
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 3));
+            }
+
+            let dormancy_interval = T::DormancySweepInterval::get();
+            if !dormancy_interval.is_zero() && (now % dormancy_interval).is_zero() {
+                Self::start_sweep(BankingTask::SweepDormancy(None));
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 3));
+            }
+
+            weight
+        }
+
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::process_tasks(remaining_weight)
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::weight(T::WeightInfo::create_account())]
@@ -103,96 +641,2004 @@ pub mod pallet {
         ) -> DispatchResult {
             let account_holder = ensure_signed(origin)?;
 
-            ensure!(
-                !BankAccounts::<T>::contains_key(&account_holder),
-                Error::<T>::AccountAlreadyExists
+            let new_account = Self::new_account(
+                account_holder.clone(),
+                account_number,
+                ifsc_code,
+                bank_name,
+                branch_name,
+                branch_address,
+                holder_dob,
+                holder_pan,
+                holder_aadhaar,
+                holder_category,
+                account_type,
+                initial_balance,
             );
 
-            let now = <frame_system::Pallet<T>>::block_number();
+            Self::open_account(account_holder, new_account)
+        }
+
+        /// Like `create_account`, but also records the MICR code after checking the IFSC,
+        /// MICR and bank name agree with each other.
+        #[pallet::weight(T::WeightInfo::create_account())]
+        pub fn create_account_full(
+            origin: OriginFor<T>,
+            account_number: Vec<u8>,
+            ifsc_code: Vec<u8>,
+            micr_code: Vec<u8>,
+            bank_name: Vec<u8>,
+            branch_name: Vec<u8>,
+            branch_address: Vec<u8>,
+            holder_dob: Option<T::Moment>,
+            holder_pan: Option<Vec<u8>>,
+            holder_aadhaar: Option<Vec<u8>>,
+            holder_category: Option<Vec<u8>>,
+            account_type: Vec<u8>,
+            initial_balance: BalanceOf<T>,
+        ) -> DispatchResult {
+            let account_holder = ensure_signed(origin)?;
+            Self::ensure_consistent_identifiers(&ifsc_code, &micr_code, &bank_name)?;
 
-            let new_account = BankingAccount {
+            let mut new_account = Self::new_account(
+                account_holder.clone(),
                 account_number,
                 ifsc_code,
-                micr_code: None,
                 bank_name,
                 branch_name,
                 branch_address,
-                account_holder: account_holder.clone(),
                 holder_dob,
                 holder_pan,
                 holder_aadhaar,
                 holder_category,
                 account_type,
-                opening_date: now,
-                status: Status::Operative,
-                current_balance: initial_balance,
-                overdraft_limit: None,
-                has_cheque_book: false,
-                has_atm_debit_card: false,
-                has_internet_banking: false,
-                has_mobile_banking: false,
-                last_txn: None,
-                parent_account: None,
-                child_accounts: Vec::new(),
-            };
+                initial_balance,
+            );
+            new_account.micr_code = Some(micr_code);
 
-            BankAccounts::<T>::insert(&account_holder, new_account);
+            Self::open_account(account_holder, new_account)
+        }
 
-            T::Currency::transfer(
-                &account_holder,
-                &Self::account_id(),
+        #[pallet::weight(T::WeightInfo::create_account())]
+        pub fn create_from_template(
+            origin: OriginFor<T>,
+            template: AccountTemplate,
+            account_number: Vec<u8>,
+            ifsc_code: Vec<u8>,
+            bank_name: Vec<u8>,
+            branch_name: Vec<u8>,
+            branch_address: Vec<u8>,
+            holder_dob: Option<T::Moment>,
+            holder_pan: Option<Vec<u8>>,
+            holder_aadhaar: Option<Vec<u8>>,
+            holder_category: Option<Vec<u8>>,
+            initial_balance: BalanceOf<T>,
+        ) -> DispatchResult {
+            let account_holder = ensure_signed(origin)?;
+
+            let mut new_account = Self::new_account(
+                account_holder.clone(),
+                account_number,
+                ifsc_code,
+                bank_name,
+                branch_name,
+                branch_address,
+                holder_dob,
+                holder_pan,
+                holder_aadhaar,
+                holder_category,
+                template.account_type(),
                 initial_balance,
-                ExistenceRequirement::KeepAlive,
-            )?;
+            );
+            Self::apply_template(&mut new_account, &template);
 
-            Self::deposit_event(Event::AccountCreated(account_holder, initial_balance));
-            Ok(())
+            Self::open_account(account_holder, new_account)
         }
 
+        /// Propose `sub_account_id` as a child of `parent`. Nothing is linked or funded until
+        /// the child accepts with `accept_sub_account`.
         #[pallet::weight(T::WeightInfo::add_sub_account())]
         pub fn add_sub_account(
             origin: OriginFor<T>,
             parent: T::AccountId,
             sub_account_id: T::AccountId,
+            initial_funding: Option<BalanceOf<T>>,
+            auth: Option<Vec<u8>>,
         ) -> DispatchResult {
-            let _caller = ensure_signed(origin)?;
+            let caller = ensure_signed(origin)?;
+            ensure!(caller == parent, Error::<T>::NotAccountHolder);
 
             ensure!(parent != sub_account_id, Error::<T>::CannotAddSelfAsChild);
-            ensure!(BankAccounts::<T>::contains_key(&parent), Error::<T>::AccountNotFound);
-            ensure!(BankAccounts::<T>::contains_key(&sub_account_id), Error::<T>::AccountNotFound);
+            let initial_funding = initial_funding.map(Self::denominated).transpose()?;
+            if initial_funding.is_some() {
+                Self::authorize(&parent, auth)?;
+            }
+            Self::load_account(&parent)?;
+            Self::ensure_can_link(&parent, &sub_account_id)?;
 
-            BankAccounts::<T>::try_mutate(&parent, |maybe_parent| {
-                let parent_account = maybe_parent.as_mut().ok_or(Error::<T>::AccountNotFound)?;
-                if !parent_account.child_accounts.contains(&sub_account_id) {
-                    parent_account.child_accounts.push(sub_account_id.clone());
-                }
-                Ok(())
-            })?;
+            PendingSubAccounts::<T>::insert(&sub_account_id, &parent, initial_funding);
+            Self::deposit_event(Event::SubAccountProposed(parent, sub_account_id));
+            Ok(())
+        }
 
-            BankAccounts::<T>::try_mutate(&sub_account_id, |maybe_sub| {
-                let sub = maybe_sub.as_mut().ok_or(Error::<T>::AccountNotFound)?;
-                sub.parent_account = Some(parent.clone());
-                Ok(())
-            })?;
+        /// Accept the caller's pending link under `parent`, receiving any funding it offered.
+        /// Accepting also consents to `consolidate` sweeping the caller into its root.
+        #[pallet::weight(T::WeightInfo::accept_sub_account())]
+        pub fn accept_sub_account(origin: OriginFor<T>, parent: T::AccountId) -> DispatchResult {
+            let child = ensure_signed(origin)?;
+
+            let initial_funding =
+                PendingSubAccounts::<T>::get(&child, &parent).ok_or(Error::<T>::NoPendingSubAccount)?;
+            // The hierarchy may have changed since the proposal
+            Self::ensure_can_link(&parent, &child)?;
+            PendingSubAccounts::<T>::remove(&child, &parent);
 
-            Self::deposit_event(Event::SubAccountAdded(parent, sub_account_id));
+            // Initial funding goes through the same outbound checks as any transfer
+            if let Some(amount) = initial_funding {
+                Self::do_transfer(&parent, &child, amount)?;
+                Self::deposit_event(Event::Transferred(parent.clone(), child.clone(), amount));
+            }
+
+            let (mut parent_account, parent_stored) = Self::load_account(&parent)?;
+            let (mut sub, sub_stored) = Self::load_account(&child)?;
+            if !parent_account.child_accounts.contains(&child) {
+                parent_account.child_accounts.push(child.clone());
+            }
+            Self::save_account(&parent, parent_account, parent_stored);
+            sub.parent_account = Some(parent.clone());
+            Self::save_account(&child, sub, sub_stored);
+
+            Self::deposit_event(Event::SubAccountAdded(parent, child));
             Ok(())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        fn account_id() -> T::AccountId {
-            // You can use PalletId if needed
-            T::PalletInfo::name::<Self>()
-                .as_bytes()
-                .using_encoded(|b| T::AccountId::decode(&mut &blake2_256(b)[..]).unwrap_or_default())
+        #[pallet::weight(T::WeightInfo::add_risk_flag())]
+        pub fn add_risk_flag(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            flag: RiskFlag,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let (mut acc, stored) = Self::load_account(&account)?;
+            ensure!(!acc.risk_flags.contains(&flag), Error::<T>::RiskFlagAlreadySet);
+            acc.risk_flags.try_push(flag.clone()).map_err(|_| Error::<T>::TooManyRiskFlags)?;
+            Self::save_account(&account, acc, stored);
+
+            Self::deposit_event(Event::RiskFlagAdded(account, flag));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::remove_risk_flag())]
+        pub fn remove_risk_flag(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            flag: RiskFlag,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let (mut acc, stored) = Self::load_account(&account)?;
+            ensure!(acc.risk_flags.contains(&flag), Error::<T>::RiskFlagNotSet);
+            acc.risk_flags.retain(|f| f != &flag);
+            Self::save_account(&account, acc, stored);
+
+            Self::deposit_event(Event::RiskFlagRemoved(account, flag));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::consolidate())]
+        pub fn consolidate(origin: OriginFor<T>, root: T::AccountId) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            ensure!(caller == root, Error::<T>::NotAccountHolder);
+            let mut ids = sp_std::vec![root.clone()];
+            ids.extend(Self::descendants(&root));
+
+            let swept = Self::with_accounts(&ids, |accounts| {
+                let (root_account, children) = accounts.split_first_mut().ok_or(Error::<T>::AccountNotFound)?;
+                let mut swept = BalanceOf::<T>::zero();
+                for (child, acc) in ids[1..].iter().zip(children.iter_mut()) {
+                    let amount = Self::free_balance(acc);
+                    // Inbound-only and non-operative children keep their funds
+                    if amount.is_zero() || acc.inbound_only || acc.status != Status::Operative {
+                        continue;
+                    }
+                    swept = swept.checked_add(&amount).ok_or(Error::<T>::BalanceOverflow)?;
+                    acc.current_balance = acc.current_balance.saturating_sub(amount);
+                    Self::note_transaction(child, acc, TxnKind::TransferOut, amount, Some(root.clone()));
+                    Self::note_transaction(&root, root_account, TxnKind::TransferIn, amount, Some(child.clone()));
+                }
+                root_account.current_balance =
+                    root_account.current_balance.checked_add(&swept).ok_or(Error::<T>::BalanceOverflow)?;
+                Ok(swept)
+            })?;
+
+            Self::deposit_event(Event::HierarchyConsolidated(root, swept));
+            Ok(())
         }
+
+        #[pallet::weight(T::WeightInfo::deposit())]
+        pub fn deposit(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let amount = Self::denominated(amount)?;
+
+            let (mut acc, stored) = Self::load_account(&who)?;
+            ensure!(acc.status == Status::Operative, Error::<T>::AccountNotOperative);
+            Self::ensure_within_txn_limit(&acc, amount)?;
+            Self::credit(&mut acc, amount)?;
+            Self::note_transaction(&who, &mut acc, TxnKind::Deposit, amount, None);
+            Self::notify(&who, &acc, NotificationKind::OnDeposit);
+            Self::save_account(&who, acc, stored);
+
+            T::Currency::transfer(
+                &who,
+                &Self::account_id(),
+                amount,
+                ExistenceRequirement::KeepAlive,
+            )
+            .map_err(Self::currency_error)?;
+
+            Self::deposit_event(Event::Deposited(who, amount));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::withdraw())]
+        pub fn withdraw(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T>,
+            auth: Option<Vec<u8>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let amount = Self::denominated(amount)?;
+            Self::authorize(&who, auth)?;
+
+            let (mut acc, stored) = Self::load_account(&who)?;
+            ensure!(acc.status == Status::Operative, Error::<T>::AccountNotOperative);
+            ensure!(!acc.inbound_only, Error::<T>::OutboundBlocked);
+            Self::ensure_within_txn_limit(&acc, amount)?;
+            let drawn_before = acc.overdraft_used;
+            Self::debit(&mut acc, amount)?;
+            Self::check_overdraft_utilization(&who, &acc, drawn_before);
+            Self::note_outflow(&who, &acc, amount)?;
+            Self::note_transaction(&who, &mut acc, TxnKind::Withdrawal, amount, None);
+            Self::notify(&who, &acc, NotificationKind::OnWithdraw);
+            Self::check_low_balance(&who, &acc);
+            Self::save_account(&who, acc, stored);
+
+            T::Currency::transfer(
+                &Self::account_id(),
+                &who,
+                amount,
+                ExistenceRequirement::AllowDeath,
+            )
+            .map_err(Self::currency_error)?;
+
+            Self::deposit_event(Event::Withdrawn(who, amount));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::transfer_between())]
+        pub fn transfer_between(
+            origin: OriginFor<T>,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+            auth: Option<Vec<u8>>,
+        ) -> DispatchResult {
+            let from = ensure_signed(origin)?;
+            let amount = Self::denominated(amount)?;
+            Self::authorize(&from, auth)?;
+            Self::do_transfer(&from, &to, amount)?;
+
+            Self::deposit_event(Event::Transferred(from, to, amount));
+            Ok(())
+        }
+
+        /// Relay a transfer `from` signed off-chain over `(b"banking/transfer", genesis hash, to,
+        /// amount, nonce)`. The holder's signature is the authorization, so no auth preimage
+        /// is taken, but an account locked by failed auth attempts still cannot send. The amount
+        /// must already be a whole number of `MinDenomination`; it is never rounded.
+        #[pallet::weight(T::WeightInfo::transfer_with_sig())]
+        pub fn transfer_with_sig(
+            origin: OriginFor<T>,
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+            nonce: u64,
+            signature: sr25519::Signature,
+        ) -> DispatchResult {
+            let _relayer = ensure_signed(origin)?;
+
+            ensure!(Self::denominated(amount)? == amount, Error::<T>::InvalidDenomination);
+            ensure!(nonce == Self::signature_nonce(&from), Error::<T>::InvalidNonce);
+
+            let signer = sr25519::Public::decode(&mut &from.encode()[..])
+                .map_err(|_| Error::<T>::InvalidSignature)?;
+            let genesis = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+            let message = (b"banking/transfer", genesis, &to, amount, nonce).encode();
+            ensure!(
+                sp_io::crypto::sr25519_verify(&signature, &message, &signer),
+                Error::<T>::InvalidSignature
+            );
+
+            let (acc, _) = Self::load_account(&from)?;
+            ensure!(
+                acc.auth_hash.is_none() || acc.failed_auth_attempts < T::MaxAuthAttempts::get(),
+                Error::<T>::AuthLocked
+            );
+            SignatureNonces::<T>::insert(&from, nonce.saturating_add(1));
+            Self::do_transfer(&from, &to, amount)?;
+
+            Self::deposit_event(Event::SignedTransferExecuted(from, to, amount, nonce));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::add_whitelist_entry())]
+        pub fn add_whitelist_entry(origin: OriginFor<T>, destination: T::AccountId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (mut acc, stored) = Self::load_account(&who)?;
+            ensure!(!acc.whitelist.contains(&destination), Error::<T>::AlreadyWhitelisted);
+            acc.whitelist
+                .try_push(destination.clone())
+                .map_err(|_| Error::<T>::TooManyWhitelistEntries)?;
+            Self::save_account(&who, acc, stored);
+
+            Self::deposit_event(Event::WhitelistEntryAdded(who, destination));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::remove_whitelist_entry())]
+        pub fn remove_whitelist_entry(origin: OriginFor<T>, destination: T::AccountId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (mut acc, stored) = Self::load_account(&who)?;
+            ensure!(acc.whitelist.contains(&destination), Error::<T>::WhitelistEntryNotFound);
+            acc.whitelist.retain(|d| d != &destination);
+            Self::save_account(&who, acc, stored);
+
+            Self::deposit_event(Event::WhitelistEntryRemoved(who, destination));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::set_whitelist_enabled())]
+        pub fn set_whitelist_enabled(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (mut acc, stored) = Self::load_account(&who)?;
+            acc.whitelist_enabled = enabled;
+            Self::save_account(&who, acc, stored);
+
+            Self::deposit_event(Event::WhitelistToggled(who, enabled));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::set_inbound_only())]
+        pub fn set_inbound_only(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::set_inbound_flag(&account, true)?;
+            Self::deposit_event(Event::InboundOnlySet(account));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::clear_inbound_only())]
+        pub fn clear_inbound_only(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            Self::set_inbound_flag(&account, false)?;
+            Self::deposit_event(Event::InboundOnlyCleared(account));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::set_notification_preferences())]
+        pub fn set_notification_preferences(
+            origin: OriginFor<T>,
+            flags: u8,
+            low_balance_threshold: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (mut acc, stored) = Self::load_account(&who)?;
+            acc.notify_flags = flags;
+            acc.low_balance_threshold = low_balance_threshold;
+            Self::save_account(&who, acc, stored);
+
+            Self::deposit_event(Event::NotificationPreferencesSet(who, flags, low_balance_threshold));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::set_spending_limit())]
+        pub fn set_spending_limit(
+            origin: OriginFor<T>,
+            period: LimitPeriod,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (mut acc, stored) = Self::load_account(&who)?;
+            acc.spending_limit = Some((period.clone(), amount));
+            Self::save_account(&who, acc, stored);
+            // Outflow so far was counted against the old period
+            PeriodOutflow::<T>::remove(&who);
+
+            Self::deposit_event(Event::SpendingLimitSet(who, period, amount));
+            Ok(())
+        }
+
+        /// Set or clear the secondary auth hash. Replacing an existing hash needs its preimage.
+        /// Preimages are sent in clear inside the extrinsic, so each one is public once used:
+        /// the secret guards against a stolen signing key only until then, and should be
+        /// replaced after every use.
+        #[pallet::weight(T::WeightInfo::set_auth_secret())]
+        pub fn set_auth_secret(
+            origin: OriginFor<T>,
+            secret_hash: Option<[u8; 32]>,
+            current: Option<Vec<u8>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::authorize(&who, current)?;
+
+            let (mut acc, stored) = Self::load_account(&who)?;
+            acc.auth_hash = secret_hash;
+            Self::save_account(&who, acc, stored);
+
+            Self::deposit_event(Event::AuthSecretSet(who, secret_hash.is_some()));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::reset_auth_attempts())]
+        pub fn reset_auth_attempts(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let (mut acc, stored) = Self::load_account(&account)?;
+            acc.failed_auth_attempts = 0;
+            Self::save_account(&account, acc, stored);
+
+            Self::deposit_event(Event::AuthAttemptsReset(account));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::set_account_metadata())]
+        pub fn set_account_metadata(
+            origin: OriginFor<T>,
+            key: BoundedVec<u8, T::MaxMetadataKey>,
+            value: BoundedVec<u8, T::MaxMetadataValue>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(BankAccounts::<T>::contains_key(&who), Error::<T>::AccountNotFound);
+
+            AccountMetadata::<T>::try_mutate(&who, |entries| {
+                match entries.iter_mut().find(|(k, _)| *k == key) {
+                    Some((_, v)) => *v = value,
+                    None => entries
+                        .try_push((key.clone(), value))
+                        .map_err(|_| Error::<T>::TooManyMetadataEntries)?,
+                }
+                Ok::<_, Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::AccountMetadataSet(who, key));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::remove_account_metadata_key())]
+        pub fn remove_account_metadata_key(
+            origin: OriginFor<T>,
+            key: BoundedVec<u8, T::MaxMetadataKey>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            AccountMetadata::<T>::try_mutate(&who, |entries| {
+                ensure!(entries.iter().any(|(k, _)| *k == key), Error::<T>::MetadataKeyNotFound);
+                entries.retain(|(k, _)| *k != key);
+                Ok::<_, Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::AccountMetadataRemoved(who, key));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::set_accrue_while_frozen())]
+        pub fn set_accrue_while_frozen(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            enabled: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let (mut acc, stored) = Self::load_account(&account)?;
+            // Settle under the old policy before switching
+            Self::settle_interest(&account, &mut acc)?;
+            acc.accrue_while_frozen = enabled;
+            Self::save_account(&account, acc, stored);
+
+            Self::deposit_event(Event::AccrueWhileFrozenSet(account, enabled));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::set_overdraft_alert())]
+        pub fn set_overdraft_alert(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            threshold: Option<Permill>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let (mut acc, stored) = Self::load_account(&account)?;
+            acc.overdraft_alert = threshold;
+            Self::save_account(&account, acc, stored);
+
+            Self::deposit_event(Event::OverdraftAlertSet(account, threshold));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::set_high_limit_override())]
+        pub fn set_high_limit_override(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            enabled: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let (mut acc, stored) = Self::load_account(&account)?;
+            acc.high_limit_override = enabled;
+            Self::save_account(&account, acc, stored);
+
+            Self::deposit_event(Event::HighLimitOverrideSet(account, enabled));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::set_capitalization())]
+        pub fn set_capitalization(origin: OriginFor<T>, frequency: CapFrequency) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (mut acc, stored) = Self::load_account(&who)?;
+            // Settle interest under the old frequency before switching, then start the new
+            // schedule now so a shorter period cannot reach back over settled blocks
+            Self::settle_interest(&who, &mut acc)?;
+            acc.capitalization = frequency.clone();
+            acc.last_capitalization = Self::now();
+            Self::save_account(&who, acc, stored);
+
+            Self::deposit_event(Event::CapitalizationSet(who, frequency));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::accrue_interest())]
+        pub fn accrue_interest(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+            let _caller = ensure_signed(origin)?;
+            Self::do_accrue_interest(&account)
+        }
+
+        /// Record a sign of life on the caller's account, waking it if it went dormant.
+        #[pallet::weight(T::WeightInfo::heartbeat())]
+        pub fn heartbeat(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (mut acc, stored) = Self::load_account(&who)?;
+            acc.last_heartbeat = Some(Self::now());
+            let reactivated = acc.status == Status::Dormant;
+            if reactivated {
+                Self::set_status(&mut acc, Status::Operative);
+            }
+            Self::save_account(&who, acc, stored);
+
+            if reactivated {
+                Self::deposit_event(Event::AccountReactivated(who.clone()));
+            }
+            Self::deposit_event(Event::Heartbeat(who));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::set_nominee())]
+        pub fn set_nominee(origin: OriginFor<T>, nominee: Option<T::AccountId>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (mut acc, stored) = Self::load_account(&who)?;
+            acc.nominee = nominee.clone();
+            Self::save_account(&who, acc, stored);
+            PendingSuccessions::<T>::remove(&who);
+
+            Self::deposit_event(Event::NomineeSet(who, nominee));
+            Ok(())
+        }
+
+        /// First call by the nominee on an inactive account starts the succession delay;
+        /// a call after the delay, with the account still untouched, completes it.
+        #[pallet::weight(T::WeightInfo::claim_succession())]
+        pub fn claim_succession(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+            let claimant = ensure_signed(origin)?;
+
+            let (acc, _) = Self::load_account(&account)?;
+            ensure!(acc.nominee.as_ref() == Some(&claimant), Error::<T>::NotNominee);
+            ensure!(acc.overdraft_used.is_zero(), Error::<T>::OutstandingOverdraft);
+
+            let now = Self::now();
+            let last_activity = Self::last_activity(&acc);
+            ensure!(
+                now.saturating_sub(last_activity) >= T::InactivityForSuccession::get(),
+                Error::<T>::AccountStillActive
+            );
+
+            match PendingSuccessions::<T>::get(&account) {
+                // Any activity since the claim started restarts the process
+                Some(started) if last_activity < started => {
+                    ensure!(
+                        now.saturating_sub(started) >= T::SuccessionDelay::get(),
+                        Error::<T>::SuccessionNotReady
+                    );
+                    ensure!(!BankAccounts::<T>::contains_key(&claimant), Error::<T>::AccountAlreadyExists);
+
+                    PendingSuccessions::<T>::remove(&account);
+                    Self::rekey_account(&account, &claimant)?;
+                    Self::deposit_event(Event::SuccessionClaimed(account, claimant));
+                }
+                _ => {
+                    PendingSuccessions::<T>::insert(&account, now);
+                    let ready_at = now.saturating_add(T::SuccessionDelay::get());
+                    Self::deposit_event(Event::SuccessionInitiated(account, claimant, ready_at));
+                }
+            }
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::detach_sub_account())]
+        pub fn detach_sub_account(origin: OriginFor<T>, child: T::AccountId) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+
+            let (mut child_account, child_stored) = Self::load_account(&child)?;
+            let parent = child_account.parent_account.clone().ok_or(Error::<T>::NotParentAccount)?;
+            // Either side may cut the link; the child withdraws its consent to being swept
+            ensure!(caller == parent || caller == child, Error::<T>::NotParentAccount);
+            let (mut parent_account, parent_stored) = Self::load_account(&parent)?;
+            child_account.parent_account = None;
+            parent_account.child_accounts.retain(|c| c != &child);
+            Self::save_account(&child, child_account, child_stored);
+            Self::save_account(&parent, parent_account, parent_stored);
+
+            Self::deposit_event(Event::SubAccountDetached(parent, child));
+            Ok(())
+        }
+
+        /// Start closing the caller's account; children must be detached within
+        /// `ClosureGracePeriod` before `finalize_closure` can complete it.
+        #[pallet::weight(T::WeightInfo::request_closure())]
+        pub fn request_closure(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (acc, _) = Self::load_account(&who)?;
+            ensure!(acc.status == Status::Operative, Error::<T>::AccountNotOperative);
+            ensure!(!PendingClosures::<T>::contains_key(&who), Error::<T>::ClosureAlreadyRequested);
+
+            let now = Self::now();
+            PendingClosures::<T>::insert(&who, now);
+            Self::deposit_event(Event::ClosureRequested(who, now.saturating_add(T::ClosureGracePeriod::get())));
+            Ok(())
+        }
+
+        /// Close a childless account with a pending request, paying out its balance. A request
+        /// whose grace period lapsed with children still attached, or whose account is no longer
+        /// operative or was locked to inbound-only since, is cancelled instead.
+        #[pallet::weight(T::WeightInfo::finalize_closure())]
+        pub fn finalize_closure(origin: OriginFor<T>, auth: Option<Vec<u8>>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let requested_at = PendingClosures::<T>::get(&who).ok_or(Error::<T>::ClosureNotRequested)?;
+            Self::authorize(&who, auth)?;
+            let (mut acc, stored) = Self::load_account(&who)?;
+
+            if acc.status != Status::Operative || acc.inbound_only {
+                PendingClosures::<T>::remove(&who);
+                Self::deposit_event(Event::ClosureCancelled(who));
+                return Ok(());
+            }
+            if !acc.child_accounts.is_empty() {
+                ensure!(
+                    Self::now().saturating_sub(requested_at) > T::ClosureGracePeriod::get(),
+                    Error::<T>::ChildrenStillAttached
+                );
+                PendingClosures::<T>::remove(&who);
+                Self::deposit_event(Event::ClosureCancelled(who));
+                return Ok(());
+            }
+            ensure!(acc.overdraft_used.is_zero(), Error::<T>::OutstandingOverdraft);
+
+            let payout = acc.current_balance;
+            if let Some(parent) = &acc.parent_account {
+                BankAccounts::<T>::mutate(parent, |maybe_parent| {
+                    if let Some(p) = maybe_parent {
+                        p.child_accounts.retain(|c| c != &who);
+                    }
+                });
+            }
+            acc.current_balance = BalanceOf::<T>::zero();
+            acc.sub_ledgers = BoundedVec::default();
+            Self::set_status(&mut acc, Status::Closed);
+            Self::issue_closure_certificate(&who, &acc, payout);
+            Self::save_account(&who, acc, stored);
+            LedgerExpiries::<T>::remove(&who);
+            PendingClosures::<T>::remove(&who);
+
+            T::Currency::transfer(
+                &Self::account_id(),
+                &who,
+                payout,
+                ExistenceRequirement::AllowDeath,
+            )
+            .map_err(Self::currency_error)?;
+
+            Self::deposit_event(Event::ClosureFinalized(who, payout));
+            Ok(())
+        }
+
+        /// Move a frozen account's entire balance to `destination` and close it.
+        #[pallet::weight(T::WeightInfo::seize_account())]
+        pub fn seize_account(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            destination: T::AccountId,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let (mut acc, stored) = Self::load_account(&account)?;
+            ensure!(acc.status == Status::Frozen, Error::<T>::AccountNotFrozen);
+            let seized = acc.current_balance;
+            acc.current_balance = BalanceOf::<T>::zero();
+            acc.sub_ledgers = BoundedVec::default();
+            Self::set_status(&mut acc, Status::Closed);
+            Self::issue_closure_certificate(&account, &acc, seized);
+            Self::save_account(&account, acc, stored);
+            LedgerExpiries::<T>::remove(&account);
+            VelocityFrozenFrom::<T>::remove(&account);
+            Seizures::<T>::insert(&account, (destination.clone(), seized, Self::now()));
+
+            T::Currency::transfer(
+                &Self::account_id(),
+                &destination,
+                seized,
+                ExistenceRequirement::AllowDeath,
+            )
+            .map_err(Self::currency_error)?;
+
+            Self::deposit_event(Event::AccountSeized(account, destination, seized));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::clear_velocity_anomaly())]
+        pub fn clear_velocity_anomaly(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let (mut acc, stored) = Self::load_account(&account)?;
+            acc.velocity_anomaly = false;
+            if let Some(previous) = VelocityFrozenFrom::<T>::take(&account) {
+                if acc.status == Status::Frozen {
+                    Self::set_status(&mut acc, previous);
+                }
+            }
+            Self::save_account(&account, acc, stored);
+            TxnVelocity::<T>::remove(&account);
+
+            Self::deposit_event(Event::VelocityAnomalyCleared(account));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::allocate_to_ledger())]
+        pub fn allocate_to_ledger(
+            origin: OriginFor<T>,
+            label: BoundedVec<u8, T::MaxLabel>,
+            amount: BalanceOf<T>,
+            expiry: Option<T::Moment>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (mut acc, stored) = Self::load_account(&who)?;
+            let allocated = Self::allocated_balance(&acc)
+                .checked_add(&amount)
+                .ok_or(Error::<T>::BalanceOverflow)?;
+            ensure!(allocated <= acc.current_balance, Error::<T>::LedgerOverAllocated);
+
+            match acc.sub_ledgers.iter_mut().find(|(l, _)| *l == label) {
+                Some((_, balance)) => *balance = balance.saturating_add(amount),
+                None => acc
+                    .sub_ledgers
+                    .try_push((label.clone(), amount))
+                    .map_err(|_| Error::<T>::TooManyLedgers)?,
+            }
+            Self::save_account(&who, acc, stored);
+            // A new expiry replaces the old one; topping up without one keeps it
+            if let Some(expiry) = expiry {
+                LedgerExpiries::<T>::try_mutate(&who, |expiries| {
+                    expiries.retain(|(l, _)| *l != label);
+                    expiries.try_push((label.clone(), expiry)).map_err(|_| Error::<T>::TooManyLedgers)
+                })?;
+            }
+
+            Self::deposit_event(Event::LedgerAllocated(who, label, amount));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::withdraw_from_ledger())]
+        pub fn withdraw_from_ledger(
+            origin: OriginFor<T>,
+            label: BoundedVec<u8, T::MaxLabel>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (mut acc, stored) = Self::load_account(&who)?;
+            let (_, balance) = acc
+                .sub_ledgers
+                .iter_mut()
+                .find(|(l, _)| *l == label)
+                .ok_or(Error::<T>::LedgerNotFound)?;
+            ensure!(amount <= *balance, Error::<T>::LedgerInsufficientFunds);
+            *balance = balance.saturating_sub(amount);
+            acc.sub_ledgers.retain(|(_, b)| !b.is_zero());
+            if !acc.sub_ledgers.iter().any(|(l, _)| *l == label) {
+                LedgerExpiries::<T>::mutate(&who, |expiries| expiries.retain(|(l, _)| *l != label));
+            }
+            Self::save_account(&who, acc, stored);
+
+            Self::deposit_event(Event::LedgerWithdrawn(who, label, amount));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::reconcile())]
+        pub fn reconcile(origin: OriginFor<T>) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let expected = Self::ledger_total();
+            let actual = T::Currency::free_balance(&Self::account_id());
+            let drift = if expected > actual { expected - actual } else { actual - expected };
+
+            // Drift beyond the tolerance fails the call, which also discards the event;
+            // the `LedgerDrift` error is what surfaces in that case.
+            ensure!(drift <= T::ReconciliationTolerance::get(), Error::<T>::LedgerDrift);
+
+            Self::deposit_event(Event::ReconciliationResult(expected, actual, drift.is_zero()));
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn account_id() -> T::AccountId {
+            // You can use PalletId if needed
+            T::PalletInfo::name::<Self>()
+                .as_bytes()
+                .using_encoded(|b| T::AccountId::decode(&mut &blake2_256(b)[..]).unwrap_or_default())
+        }
+
+        /// Current block expressed in the pallet's `Moment` type.
+        fn now() -> T::Moment {
+            let block: u64 = <frame_system::Pallet<T>>::block_number().unique_saturated_into();
+            T::Moment::unique_saturated_from(block)
+        }
+
+        /// Blocks between capitalizations for a frequency.
+        fn capitalization_period(frequency: &CapFrequency) -> T::Moment {
+            let year = T::BlocksPerYear::get();
+            let period = match frequency {
+                CapFrequency::Monthly => year / T::Moment::from(12u32),
+                CapFrequency::Quarterly => year / T::Moment::from(4u32),
+                CapFrequency::Annually => year,
+            };
+            period.max(T::Moment::from(1u32))
+        }
+
+        /// Simple interest on `principal` over `blocks` at the annual `InterestRate`.
+        fn interest_for(principal: BalanceOf<T>, blocks: T::Moment) -> BalanceOf<T> {
+            let principal: u128 = principal.unique_saturated_into();
+            let blocks: u128 = blocks.unique_saturated_into();
+            let year: u128 = T::BlocksPerYear::get().unique_saturated_into();
+            let annual = T::InterestRate::get() * principal;
+            let interest = annual.saturating_mul(blocks) / year.max(1);
+            BalanceOf::<T>::unique_saturated_from(interest)
+        }
+
+        /// Accrue interest up to `now`, folding accrued interest into the principal at each
+        /// capitalization boundary. Returns the amount capitalized.
+        fn accrue(acc: &mut BankingAccountOf<T>, now: T::Moment) -> BalanceOf<T> {
+            let period = Self::capitalization_period(&acc.capitalization);
+            let mut capitalized = BalanceOf::<T>::zero();
+
+            while acc.last_accrual < now {
+                // A boundary already accrued past is capitalized where accrual stands, never re-accrued
+                let boundary = acc.last_capitalization.saturating_add(period).max(acc.last_accrual);
+                let until = now.min(boundary);
+                let elapsed = until.saturating_sub(acc.last_accrual);
+                acc.accrued_interest = acc
+                    .accrued_interest
+                    .saturating_add(Self::interest_for(acc.current_balance, elapsed));
+                acc.last_accrual = until;
+
+                if until == boundary {
+                    acc.current_balance = acc.current_balance.saturating_add(acc.accrued_interest);
+                    capitalized = capitalized.saturating_add(acc.accrued_interest);
+                    acc.accrued_interest = BalanceOf::<T>::zero();
+                    acc.last_capitalization = boundary;
+                }
+            }
+            capitalized
+        }
+
+        /// Frozen accounts earn nothing unless `accrue_while_frozen` is set.
+        fn accrual_suspended(acc: &BankingAccountOf<T>) -> bool {
+            acc.status == Status::Frozen && !acc.accrue_while_frozen
+        }
+
+        /// Forfeit interest up to `now`, moving the capitalization boundary on by whole periods
+        /// so the schedule is kept. Interest accrued earlier is capitalized at the next boundary.
+        fn skip_accrual(acc: &mut BankingAccountOf<T>, now: T::Moment) {
+            let period = Self::capitalization_period(&acc.capitalization);
+            if !period.is_zero() {
+                let elapsed = now.saturating_sub(acc.last_capitalization);
+                let skipped = elapsed / period;
+                acc.last_capitalization = acc.last_capitalization.saturating_add(skipped.saturating_mul(period));
+            }
+            acc.last_accrual = acc.last_accrual.max(now);
+        }
+
+        /// Credit an account, paying down any drawn overdraft first. Fails rather than
+        /// saturating if the balance would overflow.
+        fn credit(acc: &mut BankingAccountOf<T>, amount: BalanceOf<T>) -> DispatchResult {
+            let repaid = amount.min(acc.overdraft_used);
+            let balance = acc
+                .current_balance
+                .checked_add(&amount.saturating_sub(repaid))
+                .ok_or(Error::<T>::BalanceOverflow)?;
+            acc.overdraft_used = acc.overdraft_used.saturating_sub(repaid);
+            acc.current_balance = balance;
+            Ok(())
+        }
+
+        /// Enforce `MaxSingleTxn` unless the account has a verified high-limit override.
+        fn ensure_within_txn_limit(acc: &BankingAccountOf<T>, amount: BalanceOf<T>) -> DispatchResult {
+            ensure!(
+                acc.high_limit_override || amount <= T::MaxSingleTxn::get(),
+                Error::<T>::TxnExceedsLimit
+            );
+            Ok(())
+        }
+
+        /// Total earmarked across an account's sub-ledgers.
+        fn allocated_balance(acc: &BankingAccountOf<T>) -> BalanceOf<T> {
+            acc.sub_ledgers
+                .iter()
+                .fold(BalanceOf::<T>::zero(), |total, (_, b)| total.saturating_add(*b))
+        }
+
+        /// Balance that can leave the account without touching the minimum balance or sub-ledgers.
+        fn free_balance(acc: &BankingAccountOf<T>) -> BalanceOf<T> {
+            acc.current_balance
+                .saturating_sub(acc.minimum_balance)
+                .saturating_sub(Self::allocated_balance(acc))
+        }
+
+        /// Debit an account down to its minimum balance, drawing on the overdraft for any shortfall.
+        fn debit(acc: &mut BankingAccountOf<T>, amount: BalanceOf<T>) -> DispatchResult {
+            let free = Self::free_balance(acc);
+            if amount <= free {
+                acc.current_balance = acc.current_balance.saturating_sub(amount);
+                return Ok(());
+            }
+
+            let shortfall = amount.saturating_sub(free);
+            let headroom = acc
+                .overdraft_limit
+                .unwrap_or_else(Zero::zero)
+                .saturating_sub(acc.overdraft_used);
+            ensure!(shortfall <= headroom, Error::<T>::InsufficientBalance);
+
+            acc.current_balance = acc.current_balance.saturating_sub(free);
+            acc.overdraft_used = acc.overdraft_used.saturating_add(shortfall);
+            Ok(())
+        }
+
+        /// Translate a `Currency` failure into a pallet error, keeping insufficient-funds
+        /// failures distinguishable from everything else.
+        fn currency_error(err: DispatchError) -> DispatchError {
+            match err {
+                DispatchError::Token(TokenError::FundsUnavailable)
+                | DispatchError::Token(TokenError::BelowMinimum)
+                | DispatchError::Token(TokenError::Frozen)
+                | DispatchError::Arithmetic(ArithmeticError::Underflow)
+                | DispatchError::Module(ModuleError { message: Some("InsufficientBalance"), .. }) => {
+                    Error::<T>::InsufficientBalance.into()
+                }
+                _ => Error::<T>::TransferFailed.into(),
+            }
+        }
+
+        /// Enforce `MinDenomination` on a transaction amount, rejecting or rounding down per policy.
+        /// When rounding, the remainder simply stays where it was.
+        fn denominated(amount: BalanceOf<T>) -> Result<BalanceOf<T>, DispatchError> {
+            let unit = T::MinDenomination::get();
+            if unit.is_zero() {
+                return Ok(amount);
+            }
+
+            let remainder = amount % unit;
+            let rounded = match T::DenominationPolicy::get() {
+                DenominationPolicy::Reject => {
+                    ensure!(remainder.is_zero(), Error::<T>::InvalidDenomination);
+                    amount
+                }
+                DenominationPolicy::RoundDown => amount.saturating_sub(remainder),
+            };
+            ensure!(!rounded.is_zero(), Error::<T>::InvalidDenomination);
+            Ok(rounded)
+        }
+
+        fn do_transfer(from: &T::AccountId, to: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            ensure!(from != to, Error::<T>::CannotTransferToSelf);
+
+            Self::with_accounts(&[from.clone(), to.clone()], |accounts| {
+                let (acc, dest) = match accounts {
+                    [acc, dest] => (acc, dest),
+                    _ => return Err(Error::<T>::AccountNotFound.into()),
+                };
+                ensure!(acc.status == Status::Operative, Error::<T>::AccountNotOperative);
+                ensure!(!acc.inbound_only, Error::<T>::OutboundBlocked);
+                ensure!(
+                    !acc.whitelist_enabled || acc.whitelist.contains(to),
+                    Error::<T>::DestinationNotWhitelisted
+                );
+                Self::ensure_within_txn_limit(acc, amount)?;
+                let drawn_before = acc.overdraft_used;
+                Self::debit(acc, amount)?;
+                Self::check_overdraft_utilization(from, acc, drawn_before);
+                Self::note_pair_transfer(from, to, amount)?;
+                Self::note_outflow(from, acc, amount)?;
+                Self::note_transaction(from, acc, TxnKind::TransferOut, amount, Some(to.clone()));
+                Self::check_low_balance(from, acc);
+                ensure!(dest.status == Status::Operative, Error::<T>::AccountNotOperative);
+                Self::credit(dest, amount)?;
+                Self::note_transaction(to, dest, TxnKind::TransferIn, amount, Some(from.clone()));
+                Ok(())
+            })
+        }
+
+        /// Load every account in `ids` (which must be distinct), run `f` over them in the same
+        /// order and write them all back only if it succeeds. Runs in a storage transaction, so
+        /// anything else `f` writes is rolled back with it and a multi-account operation never
+        /// leaves some accounts updated and others not.
+        fn with_accounts<R>(
+            ids: &[T::AccountId],
+            f: impl FnOnce(&mut [BankingAccountOf<T>]) -> Result<R, DispatchError>,
+        ) -> Result<R, DispatchError> {
+            storage::with_transaction(|| {
+                let outcome = ids
+                    .iter()
+                    .map(Self::load_account)
+                    .collect::<Result<Vec<_>, Error<T>>>()
+                    .map_err(DispatchError::from)
+                    .and_then(|loaded| {
+                        let (mut accounts, stored): (Vec<_>, Vec<_>) = loaded.into_iter().unzip();
+                        let result = f(&mut accounts)?;
+                        for ((id, acc), stored) in ids.iter().zip(accounts).zip(stored) {
+                            Self::save_account(id, acc, stored);
+                        }
+                        Ok(result)
+                    });
+                match outcome {
+                    Ok(_) => TransactionOutcome::Commit(outcome),
+                    Err(_) => TransactionOutcome::Rollback(outcome),
+                }
+            })
+        }
+
+        fn do_accrue_interest(account: &T::AccountId) -> DispatchResult {
+            let (mut acc, stored) = Self::load_account(account)?;
+            Self::settle_interest(account, &mut acc)?;
+            Self::save_account(account, acc, stored);
+            Ok(())
+        }
+
+        /// Accrue interest up to now, funding whatever gets capitalized from the treasury.
+        /// Every path that settles interest goes through here.
+        fn settle_interest(account: &T::AccountId, acc: &mut BankingAccountOf<T>) -> DispatchResult {
+            if Self::accrual_suspended(acc) {
+                Self::skip_accrual(acc, Self::now());
+                return Ok(());
+            }
+            let capitalized = Self::accrue(acc, Self::now());
+            if !capitalized.is_zero() {
+                // Interest is paid by the treasury so the pallet account keeps backing the ledger
+                T::Currency::transfer(
+                    &T::Treasury::get(),
+                    &Self::account_id(),
+                    capitalized,
+                    ExistenceRequirement::AllowDeath,
+                )
+                .map_err(Self::currency_error)?;
+                Self::deposit_event(Event::InterestCapitalized(account.clone(), capitalized));
+            }
+            Ok(())
+        }
+
+        fn enqueue_task(task: BankingTask<T::AccountId>) {
+            let tail = TaskTail::<T>::get();
+            TaskQueue::<T>::insert(tail, task);
+            TaskTail::<T>::put(tail.saturating_add(1));
+        }
+
+        /// Queue the first step of a sweep unless a pass of the same kind is still running,
+        /// so a backlog of idle work never holds overlapping passes over the same storage.
+        fn start_sweep(task: BankingTask<T::AccountId>) {
+            let kind = task.kind();
+            if SweepsPending::<T>::get(kind) {
+                return;
+            }
+            SweepsPending::<T>::insert(kind, true);
+            Self::enqueue_task(task);
+        }
+
+        /// Run queued tasks in order until the queue is empty or the weight budget runs out.
+        fn process_tasks(remaining_weight: Weight) -> Weight {
+            let overhead = T::DbWeight::get().reads_writes(2, 1);
+            let per_task = T::WeightInfo::process_task();
+            let mut used = overhead;
+            if !remaining_weight.all_gte(used) {
+                return Weight::zero();
+            }
+
+            let tail = TaskTail::<T>::get();
+            let mut head = TaskHead::<T>::get();
+            while head < tail && remaining_weight.all_gte(used.saturating_add(per_task)) {
+                if let Some(task) = TaskQueue::<T>::take(head) {
+                    Self::run_task(task);
+                }
+                head = head.saturating_add(1);
+                used = used.saturating_add(per_task);
+            }
+            TaskHead::<T>::put(head);
+            used
+        }
+
+        fn run_task(task: BankingTask<T::AccountId>) {
+            let kind = task.kind();
+            let more = match task {
+                BankingTask::SweepInterest(previous) => {
+                    let mut accounts = match previous {
+                        Some(prev) => BankAccounts::<T>::iter_keys_from(BankAccounts::<T>::hashed_key_for(&prev)),
+                        None => BankAccounts::<T>::iter_keys(),
+                    };
+                    if let Some(next) = accounts.next() {
+                        // A failed accrual (e.g. an underfunded treasury) is retried next sweep
+                        let _ = Self::do_accrue_interest(&next);
+                        Self::enqueue_task(BankingTask::SweepInterest(Some(next)));
+                        true
+                    } else {
+                        false
+                    }
+                }
+                BankingTask::PrunePairTransfers(previous) => {
+                    let mut pairs = match previous {
+                        Some((from, to)) => {
+                            PairTransfers::<T>::iter_from(PairTransfers::<T>::hashed_key_for(&from, &to))
+                        }
+                        None => PairTransfers::<T>::iter(),
+                    };
+                    if let Some((from, to, (window_start, _))) = pairs.next() {
+                        if Self::now().saturating_sub(window_start) >= T::PairTransferWindow::get() {
+                            PairTransfers::<T>::remove(&from, &to);
+                        }
+                        Self::enqueue_task(BankingTask::PrunePairTransfers(Some((from, to))));
+                        true
+                    } else {
+                        false
+                    }
+                }
+                BankingTask::SweepExpiredLedgers(previous) => {
+                    let mut accounts = match previous {
+                        Some(prev) => LedgerExpiries::<T>::iter_keys_from(LedgerExpiries::<T>::hashed_key_for(&prev)),
+                        None => LedgerExpiries::<T>::iter_keys(),
+                    };
+                    if let Some(next) = accounts.next() {
+                        Self::release_expired_ledgers(&next);
+                        Self::enqueue_task(BankingTask::SweepExpiredLedgers(Some(next)));
+                        true
+                    } else {
+                        false
+                    }
+                }
+                BankingTask::SweepDormancy(previous) => {
+                    let mut accounts = match previous {
+                        Some(prev) => BankAccounts::<T>::iter_keys_from(BankAccounts::<T>::hashed_key_for(&prev)),
+                        None => BankAccounts::<T>::iter_keys(),
+                    };
+                    if let Some(next) = accounts.next() {
+                        Self::check_dormancy(&next);
+                        Self::enqueue_task(BankingTask::SweepDormancy(Some(next)));
+                        true
+                    } else {
+                        false
+                    }
+                }
+            };
+            if !more {
+                SweepsPending::<T>::remove(kind);
+            }
+        }
+
+        /// Mark an operative account dormant once `DormancyPeriod` passes without activity,
+        /// warning the holder once when it comes within `DormancyWarningPeriod` of that.
+        fn check_dormancy(who: &T::AccountId) {
+            let period = T::DormancyPeriod::get();
+            let (mut acc, stored) = match Self::load_account(who) {
+                Ok((acc, stored)) if acc.status == Status::Operative && !period.is_zero() => (acc, stored),
+                _ => return,
+            };
+            let now = Self::now();
+            let dormant_at = Self::last_activity(&acc).saturating_add(period);
+
+            if now >= dormant_at {
+                Self::set_status(&mut acc, Status::Dormant);
+                Self::save_account(who, acc, stored);
+                DormancyWarned::<T>::remove(who);
+                Self::deposit_event(Event::AccountDormant(who.clone()));
+            } else if now.saturating_add(T::DormancyWarningPeriod::get()) >= dormant_at
+                // Activity since the last warning moves the date, which earns a fresh warning
+                && DormancyWarned::<T>::get(who) != Some(dormant_at)
+            {
+                DormancyWarned::<T>::insert(who, dormant_at);
+                Self::deposit_event(Event::DormancyWarning(who.clone(), dormant_at));
+            }
+        }
+
+        /// Drop an account's earmarks whose expiry has passed, returning their funds to the
+        /// spendable balance.
+        fn release_expired_ledgers(who: &T::AccountId) {
+            let now = Self::now();
+            let (expired, live): (Vec<_>, Vec<_>) = LedgerExpiries::<T>::get(who)
+                .into_iter()
+                .partition(|(_, expiry)| *expiry <= now);
+            if expired.is_empty() {
+                return;
+            }
+
+            if let Ok((mut acc, stored)) = Self::load_account(who) {
+                for (label, _) in expired {
+                    if let Some((_, amount)) = acc.sub_ledgers.iter().find(|(l, _)| *l == label).cloned() {
+                        acc.sub_ledgers.retain(|(l, _)| *l != label);
+                        Self::deposit_event(Event::HoldExpired(who.clone(), label, amount));
+                    }
+                }
+                Self::save_account(who, acc, stored);
+            }
+            if live.is_empty() {
+                LedgerExpiries::<T>::remove(who);
+            } else {
+                // Never longer than before, so the bound still holds
+                LedgerExpiries::<T>::insert(who, BoundedVec::truncate_from(live));
+            }
+        }
+
+        /// Check an IFSC (4-letter bank code, `0`, 6-character branch code) and a MICR
+        /// (3-digit city, bank and branch codes) are well formed and, when the registry knows
+        /// `bank_name`, that both carry that bank's codes.
+        fn ensure_consistent_identifiers(ifsc: &[u8], micr: &[u8], bank_name: &[u8]) -> DispatchResult {
+            let ifsc_ok = ifsc.len() == 11
+                && ifsc[..4].iter().all(|c| c.is_ascii_uppercase())
+                && ifsc[4] == b'0'
+                && ifsc[5..].iter().all(|c| c.is_ascii_alphanumeric());
+            let micr_ok = micr.len() == 9 && micr.iter().all(|c| c.is_ascii_digit());
+            ensure!(ifsc_ok && micr_ok, Error::<T>::InvalidBankIdentifiers);
+
+            if let Some((ifsc_prefix, micr_bank)) = T::BankRegistry::bank_codes(bank_name) {
+                ensure!(
+                    ifsc[..4] == ifsc_prefix[..] && micr[3..6] == micr_bank[..],
+                    Error::<T>::InvalidBankIdentifiers
+                );
+            }
+            Ok(())
+        }
+
+        /// Fetch an account record for modification, along with the position it is stored with;
+        /// pass both to `save_account` to persist it.
+        fn load_account(who: &T::AccountId) -> Result<(BankingAccountOf<T>, Position<BalanceOf<T>>), Error<T>> {
+            let acc = BankAccounts::<T>::get(who).ok_or(Error::<T>::AccountNotFound)?;
+            let stored = Position { balance: acc.current_balance, overdraft: acc.overdraft_used };
+            Ok((acc, stored))
+        }
+
+        /// Write back an account record fetched with `load_account`, moving `TotalDeposits` and
+        /// `TotalOverdraft` by the change from its `stored` position so every credit, debit,
+        /// overdraft draw and interest payment is reflected.
+        fn save_account(who: &T::AccountId, acc: BankingAccountOf<T>, stored: Position<BalanceOf<T>>) {
+            if acc.current_balance != stored.balance {
+                TotalDeposits::<T>::mutate(|total| {
+                    *total = total.saturating_add(acc.current_balance).saturating_sub(stored.balance);
+                });
+            }
+            if acc.overdraft_used != stored.overdraft {
+                TotalOverdraft::<T>::mutate(|total| {
+                    *total = total.saturating_add(acc.overdraft_used).saturating_sub(stored.overdraft);
+                });
+            }
+            BankAccounts::<T>::insert(who, acc)
+        }
+
+        /// Archive and announce the hash of a closed account's final state, with the balance
+        /// paid out on closure, so the closure can be verified off-chain.
+        fn issue_closure_certificate(who: &T::AccountId, acc: &BankingAccountOf<T>, final_balance: BalanceOf<T>) {
+            let now = Self::now();
+            let state_hash = sp_io::hashing::blake2_256(&acc.encode());
+            ClosedAccounts::<T>::insert(who, (now, state_hash, acc.clone()));
+            Self::deposit_event(Event::AccountClosureCertificate(who.clone(), final_balance, now, state_hash));
+        }
+
+        /// Change an account's status, keeping the per-status counts in step.
+        fn set_status(acc: &mut BankingAccountOf<T>, status: Status) {
+            if acc.status == status {
+                return;
+            }
+            StatusCounts::<T>::mutate(&acc.status, |count| *count = count.saturating_sub(1));
+            StatusCounts::<T>::mutate(&status, |count| *count = count.saturating_add(1));
+            acc.status = status;
+        }
+
+        /// Emit an `AccountNotification` if the holder opted in to this kind.
+        fn notify(who: &T::AccountId, acc: &BankingAccountOf<T>, kind: NotificationKind) {
+            if acc.notify_flags & kind.flag() != 0 {
+                Self::deposit_event(Event::AccountNotification(who.clone(), kind));
+            }
+        }
+
+        /// Raise `OverdraftUtilizationHigh` when a debit takes the drawn overdraft from below the
+        /// account's alert threshold to at or above it. Informational only.
+        fn check_overdraft_utilization(who: &T::AccountId, acc: &BankingAccountOf<T>, drawn_before: BalanceOf<T>) {
+            let (threshold, limit) = match (acc.overdraft_alert, acc.overdraft_limit) {
+                (Some(threshold), Some(limit)) if !limit.is_zero() => (threshold, limit),
+                _ => return,
+            };
+            let before = Permill::from_rational(drawn_before, limit);
+            let after = Permill::from_rational(acc.overdraft_used, limit);
+            if before < threshold && after >= threshold {
+                Self::deposit_event(Event::OverdraftUtilizationHigh(who.clone(), after));
+            }
+        }
+
+        fn check_low_balance(who: &T::AccountId, acc: &BankingAccountOf<T>) {
+            if acc.current_balance < acc.low_balance_threshold {
+                Self::notify(who, acc, NotificationKind::OnLowBalance);
+            }
+        }
+
+        /// Check `preimage` against the account's auth hash, failing with `AuthFailed` on a
+        /// mismatch. The failed call's writes are rolled back, so the attempt is counted by
+        /// `CountFailedAuth` after dispatch; the account locks once `MaxAuthAttempts` is reached.
+        /// The preimage is published in clear in the extrinsic that carries it.
+        fn authorize(who: &T::AccountId, preimage: Option<Vec<u8>>) -> DispatchResult {
+            let (mut acc, stored) = Self::load_account(who)?;
+            let expected = match acc.auth_hash {
+                Some(hash) => hash,
+                None => return Ok(()),
+            };
+            ensure!(acc.failed_auth_attempts < T::MaxAuthAttempts::get(), Error::<T>::AuthLocked);
+            let preimage = preimage.ok_or(Error::<T>::AuthRequired)?;
+            ensure!(sp_io::hashing::blake2_256(&preimage) == expected, Error::<T>::AuthFailed);
+
+            if !acc.failed_auth_attempts.is_zero() {
+                acc.failed_auth_attempts = 0;
+                Self::save_account(who, acc, stored);
+            }
+            Ok(())
+        }
+
+        /// Count a wrong auth preimage against `who`. Called by `CountFailedAuth` once the
+        /// failed call has been rolled back, so the count survives it.
+        pub(crate) fn note_failed_auth(who: &T::AccountId) {
+            BankAccounts::<T>::mutate(who, |acc| {
+                if let Some(acc) = acc {
+                    acc.failed_auth_attempts = acc.failed_auth_attempts.saturating_add(1);
+                    Self::deposit_event(Event::AuthFailed(who.clone(), acc.failed_auth_attempts));
+                }
+            });
+        }
+
+        fn set_inbound_flag(account: &T::AccountId, inbound_only: bool) -> DispatchResult {
+            let (mut acc, stored) = Self::load_account(account)?;
+            acc.inbound_only = inbound_only;
+            Self::save_account(account, acc, stored);
+            Ok(())
+        }
+
+        /// Add `amount` to the running total sent from `from` to `to`, starting a fresh window
+        /// once the previous one has lapsed.
+        fn note_pair_transfer(from: &T::AccountId, to: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            let now = Self::now();
+            let (window_start, sent) = match PairTransfers::<T>::get(from, to) {
+                Some((start, sent)) if now.saturating_sub(start) < T::PairTransferWindow::get() => (start, sent),
+                _ => (now, BalanceOf::<T>::zero()),
+            };
+            let sent = sent.saturating_add(amount);
+            ensure!(sent <= T::MaxPairTransferPerWindow::get(), Error::<T>::PairLimitExceeded);
+            PairTransfers::<T>::insert(from, to, (window_start, sent));
+            Ok(())
+        }
+
+        /// Latest sign of life on an account: opening, last transaction or heartbeat.
+        fn last_activity(acc: &BankingAccountOf<T>) -> T::Moment {
+            let mut latest = acc.opening_date;
+            if let Some(txn) = acc.last_txn {
+                latest = latest.max(txn);
+            }
+            if let Some(beat) = acc.last_heartbeat {
+                latest = latest.max(beat);
+            }
+            latest
+        }
+
+        /// Move an account to a new holder, repointing its parent and children.
+        fn rekey_account(old: &T::AccountId, new: &T::AccountId) -> DispatchResult {
+            let (acc, _) = Self::load_account(old)?;
+            let mut ids = sp_std::vec![old.clone()];
+            for linked in acc.parent_account.iter().chain(acc.child_accounts.iter()) {
+                if !ids.contains(linked) {
+                    ids.push(linked.clone());
+                }
+            }
+
+            Self::with_accounts(&ids, |accounts| {
+                let (acc, linked) = accounts.split_first_mut().ok_or(Error::<T>::AccountNotFound)?;
+                acc.account_holder = new.clone();
+                acc.nominee = None;
+                // Repoint the parent's child entry and each child's parent link
+                for other in linked.iter_mut() {
+                    for child in other.child_accounts.iter_mut().filter(|c| *c == old) {
+                        *child = new.clone();
+                    }
+                    if other.parent_account.as_ref() == Some(old) {
+                        other.parent_account = Some(new.clone());
+                    }
+                }
+                Ok(())
+            })?;
+            let acc = BankAccounts::<T>::take(old).ok_or(Error::<T>::AccountNotFound)?;
+
+            TxnVelocity::<T>::remove(old);
+            if let Some(previous) = VelocityFrozenFrom::<T>::take(old) {
+                VelocityFrozenFrom::<T>::insert(new, previous);
+            }
+            PeriodOutflow::<T>::remove(old);
+            DailyOutflow::<T>::remove(old);
+            DormancyWarned::<T>::remove(old);
+            RecentTxns::<T>::insert(new, RecentTxns::<T>::take(old));
+            AccountMetadata::<T>::insert(new, AccountMetadata::<T>::take(old));
+            LedgerExpiries::<T>::insert(new, LedgerExpiries::<T>::take(old));
+            BankAccounts::<T>::insert(new, acc);
+            Ok(())
+        }
+
+        /// Stamp the transaction time and flag accounts whose activity spikes within a window.
+        fn note_transaction(
+            who: &T::AccountId,
+            acc: &mut BankingAccountOf<T>,
+            kind: TxnKind,
+            amount: BalanceOf<T>,
+            counterparty: Option<T::AccountId>,
+        ) {
+            let now = Self::now();
+            acc.last_txn = Some(now);
+            let outflow = matches!(kind, TxnKind::Withdrawal | TxnKind::TransferOut);
+
+            RecentTxns::<T>::mutate(who, |records| {
+                if records.is_full() {
+                    records.remove(0);
+                }
+                let _ = records.try_push(TxnRecord { kind, amount, counterparty, at: now });
+            });
+
+            // Only outflows count towards velocity: anyone can credit an account, so counting
+            // inflows would let a stranger trip the anomaly, and the freeze, on someone else
+            if !outflow {
+                return;
+            }
+            let (window_start, count) = match TxnVelocity::<T>::get(who) {
+                Some((start, count)) if now.saturating_sub(start) < T::VelocityWindow::get() => (start, count),
+                _ => (now, 0),
+            };
+            let count = count.saturating_add(1);
+            TxnVelocity::<T>::insert(who, (window_start, count));
+
+            if count > T::VelocityThreshold::get() && !acc.velocity_anomaly {
+                acc.velocity_anomaly = true;
+                if T::FreezeOnVelocityAnomaly::get() && acc.status != Status::Frozen {
+                    VelocityFrozenFrom::<T>::insert(who, acc.status.clone());
+                    Self::set_status(acc, Status::Frozen);
+                }
+                Self::deposit_event(Event::VelocityAnomalyDetected(who.clone(), count));
+            }
+        }
+
+        fn new_account(
+            account_holder: T::AccountId,
+            account_number: Vec<u8>,
+            ifsc_code: Vec<u8>,
+            bank_name: Vec<u8>,
+            branch_name: Vec<u8>,
+            branch_address: Vec<u8>,
+            holder_dob: Option<T::Moment>,
+            holder_pan: Option<Vec<u8>>,
+            holder_aadhaar: Option<Vec<u8>>,
+            holder_category: Option<Vec<u8>>,
+            account_type: Vec<u8>,
+            initial_balance: BalanceOf<T>,
+        ) -> BankingAccountOf<T> {
+            let now = Self::now();
+
+            BankingAccount {
+                account_number,
+                ifsc_code,
+                micr_code: None,
+                bank_name,
+                branch_name,
+                branch_address,
+                account_holder,
+                holder_dob,
+                holder_pan,
+                holder_aadhaar,
+                holder_category,
+                account_type,
+                opening_date: now,
+                status: Status::Operative,
+                current_balance: initial_balance,
+                minimum_balance: T::MinimumBalance::get(),
+                overdraft_limit: None,
+                overdraft_used: BalanceOf::<T>::zero(),
+                overdraft_alert: None,
+                capitalization: CapFrequency::Annually,
+                accrued_interest: BalanceOf::<T>::zero(),
+                last_accrual: now,
+                last_capitalization: now,
+                accrue_while_frozen: false,
+                has_cheque_book: false,
+                has_atm_debit_card: false,
+                has_internet_banking: false,
+                has_mobile_banking: false,
+                last_txn: None,
+                last_heartbeat: None,
+                nominee: None,
+                parent_account: None,
+                child_accounts: Vec::new(),
+                risk_flags: BoundedVec::default(),
+                high_limit_override: false,
+                velocity_anomaly: false,
+                inbound_only: false,
+                notify_flags: 0,
+                low_balance_threshold: BalanceOf::<T>::zero(),
+                spending_limit: None,
+                auth_hash: None,
+                failed_auth_attempts: 0,
+                sub_ledgers: BoundedVec::default(),
+                whitelist_enabled: false,
+                whitelist: BoundedVec::default(),
+            }
+        }
+
+        fn apply_template(acc: &mut BankingAccountOf<T>, template: &AccountTemplate) {
+            match template {
+                AccountTemplate::BasicSavings => {
+                    acc.minimum_balance = T::MinimumBalance::get();
+                    acc.overdraft_limit = None;
+                    acc.has_cheque_book = false;
+                }
+                AccountTemplate::Current => {
+                    acc.minimum_balance = T::CurrentMinimumBalance::get();
+                    acc.overdraft_limit = Some(T::CurrentOverdraftLimit::get());
+                    acc.has_cheque_book = true;
+                }
+                AccountTemplate::SalaryAccount => {
+                    acc.minimum_balance = BalanceOf::<T>::zero();
+                    acc.overdraft_limit = None;
+                    acc.has_cheque_book = true;
+                }
+            }
+            acc.has_atm_debit_card = true;
+            acc.has_internet_banking = true;
+            acc.has_mobile_banking = true;
+        }
+
+        fn open_account(account_holder: T::AccountId, new_account: BankingAccountOf<T>) -> DispatchResult {
+            ensure!(
+                !BankAccounts::<T>::contains_key(&account_holder),
+                Error::<T>::AccountAlreadyExists
+            );
+
+            let initial_balance = new_account.current_balance;
+            StatusCounts::<T>::mutate(&new_account.status, |count| *count = count.saturating_add(1));
+            Self::save_account(&account_holder, new_account, Position::default());
+            TotalAccounts::<T>::mutate(|count| *count = count.saturating_add(1));
+
+            T::Currency::transfer(
+                &account_holder,
+                &Self::account_id(),
+                initial_balance,
+                ExistenceRequirement::KeepAlive,
+            )
+            .map_err(Self::currency_error)?;
+
+            Self::deposit_event(Event::AccountCreated(account_holder.clone(), initial_balance));
+            Self::grant_welcome_credit(&account_holder);
+            Ok(())
+        }
+
+        /// Gift `WelcomeCredit` from the treasury to a holder's first account.
+        fn grant_welcome_credit(holder: &T::AccountId) {
+            let credit = T::WelcomeCredit::get();
+            if credit.is_zero() || WelcomeCredited::<T>::get(holder) {
+                return;
+            }
+            let (mut acc, stored) = match Self::load_account(holder) {
+                Ok(loaded) => loaded,
+                Err(_) => return,
+            };
+            // A credit that would overflow the opening balance is not granted
+            if Self::credit(&mut acc, credit).is_err() {
+                return;
+            }
+            // An underfunded treasury skips the gift rather than blocking account creation
+            let funded = T::Currency::transfer(
+                &T::Treasury::get(),
+                &Self::account_id(),
+                credit,
+                ExistenceRequirement::AllowDeath,
+            )
+            .is_ok();
+            if !funded {
+                return;
+            }
+            Self::save_account(holder, acc, stored);
+            WelcomeCredited::<T>::insert(holder, true);
+            Self::deposit_event(Event::WelcomeCreditGranted(holder.clone(), credit));
+        }
+
+        /// Length of a limit period in blocks; weeks are seven days and months thirty.
+        fn period_length(period: &LimitPeriod) -> T::Moment {
+            let day = T::BlocksPerDay::get();
+            let length = match period {
+                LimitPeriod::Daily => day,
+                LimitPeriod::Weekly => day.saturating_mul(T::Moment::from(7u32)),
+                LimitPeriod::Monthly => day.saturating_mul(T::Moment::from(30u32)),
+            };
+            length.max(T::Moment::from(1u32))
+        }
+
+        /// Count `amount` against the risk-tiered daily limit and, when set, the holder's own
+        /// limit for its period. Each starts afresh when its period index rolls over.
+        fn note_outflow(who: &T::AccountId, acc: &BankingAccountOf<T>, amount: BalanceOf<T>) -> DispatchResult {
+            let now = Self::now();
+            let day = now / Self::period_length(&LimitPeriod::Daily);
+            let spent_today = Self::spent_in(DailyOutflow::<T>::get(who), day).saturating_add(amount);
+            ensure!(spent_today <= Self::daily_limit(acc), Error::<T>::SpendingLimitExceeded);
+
+            if let Some((period, limit)) = &acc.spending_limit {
+                let index = now / Self::period_length(period);
+                let spent = Self::spent_in(PeriodOutflow::<T>::get(who), index).saturating_add(amount);
+                ensure!(spent <= *limit, Error::<T>::SpendingLimitExceeded);
+                PeriodOutflow::<T>::insert(who, (index, spent));
+            }
+            DailyOutflow::<T>::insert(who, (day, spent_today));
+            Ok(())
+        }
+
+        /// Outflow recorded for period `index`, or zero if the record is from an earlier one.
+        fn spent_in(record: Option<(T::Moment, BalanceOf<T>)>, index: T::Moment) -> BalanceOf<T> {
+            match record {
+                Some((current, spent)) if current == index => spent,
+                _ => BalanceOf::<T>::zero(),
+            }
+        }
+
+        /// Daily outflow limit for an account; high-risk accounts get the tighter limit.
+        pub fn daily_limit(acc: &BankingAccountOf<T>) -> BalanceOf<T> {
+            if acc.risk_flags.contains(&RiskFlag::HighRisk) {
+                T::HighRiskDailyLimit::get()
+            } else {
+                T::DailyLimit::get()
+            }
+        }
+
+        /// Visit every account below `root`, excluding `root` itself, down to `max_depth` levels.
+        /// Only children whose `parent_account` points back are followed, so an account is never
+        /// reached through a link it did not accept. Iterative, and each account is visited at
+        /// most once, so corrupted cyclic links still terminate.
+        fn walk_descendants(
+            root: &T::AccountId,
+            max_depth: u32,
+            mut f: impl FnMut(&T::AccountId, &BankingAccountOf<T>),
+        ) {
+            let mut visited = sp_std::vec![root.clone()];
+            let mut stack: Vec<(T::AccountId, T::AccountId, u32)> = match BankAccounts::<T>::get(root) {
+                Some(acc) => acc.child_accounts.into_iter().map(|c| (c, root.clone(), 1)).collect(),
+                None => return,
+            };
+            while let Some((next, parent, depth)) = stack.pop() {
+                if depth > max_depth || visited.contains(&next) {
+                    continue;
+                }
+                if let Some(acc) = BankAccounts::<T>::get(&next) {
+                    if acc.parent_account.as_ref() != Some(&parent) {
+                        continue;
+                    }
+                    visited.push(next.clone());
+                    f(&next, &acc);
+                    stack.extend(acc.child_accounts.iter().cloned().map(|c| (c, next.clone(), depth + 1)));
+                }
+            }
+        }
+
+        /// Check `child` may be linked under `parent`: it exists, has no parent yet, and is not
+        /// an ancestor of `parent`.
+        fn ensure_can_link(parent: &T::AccountId, child: &T::AccountId) -> DispatchResult {
+            let (sub, _) = Self::load_account(child)?;
+            ensure!(sub.parent_account.is_none(), Error::<T>::AlreadyHasParent);
+
+            // An account cannot become a child of its own descendant
+            let mut creates_cycle = false;
+            Self::walk_ancestors(parent, T::MaxHierarchyDepth::get(), |ancestor, _| {
+                creates_cycle |= ancestor == child;
+            });
+            ensure!(!creates_cycle, Error::<T>::HierarchyCycle);
+            Ok(())
+        }
+
+        /// Visit each ancestor of `start`, nearest first, up to `max_depth` levels, stopping
+        /// at the first revisited account.
+        fn walk_ancestors(
+            start: &T::AccountId,
+            max_depth: u32,
+            mut f: impl FnMut(&T::AccountId, &BankingAccountOf<T>),
+        ) {
+            let mut visited = sp_std::vec![start.clone()];
+            let mut next = BankAccounts::<T>::get(start).and_then(|acc| acc.parent_account);
+            while let Some(parent) = next {
+                if visited.len() as u32 > max_depth || visited.contains(&parent) {
+                    break;
+                }
+                let acc = match BankAccounts::<T>::get(&parent) {
+                    Some(acc) => acc,
+                    None => break,
+                };
+                f(&parent, &acc);
+                next = acc.parent_account.clone();
+                visited.push(parent);
+            }
+        }
+
+        /// All accounts below `root` in the hierarchy, excluding `root` itself.
+        fn descendants(root: &T::AccountId) -> Vec<T::AccountId> {
+            let mut found = Vec::new();
+            Self::walk_descendants(root, T::MaxHierarchyDepth::get(), |who, _| found.push(who.clone()));
+            found
+        }
+
+        /// Combined balance of `root` and all its descendants, with the number of accounts counted.
+        pub fn net_position(root: &T::AccountId) -> (BalanceOf<T>, u32) {
+            let (mut total, mut count) = match BankAccounts::<T>::get(root) {
+                Some(acc) => (acc.current_balance, 1u32),
+                None => (BalanceOf::<T>::zero(), 0u32),
+            };
+            Self::walk_descendants(root, T::MaxHierarchyDepth::get(), |_, acc| {
+                total = total.saturating_add(acc.current_balance);
+                count += 1;
+            });
+            (total, count)
+        }
+
+        /// Funds the pallet account should hold: every ledger balance less overdraft already paid
+        /// out, from the running totals so it costs the same however many accounts there are.
+        pub fn ledger_total() -> BalanceOf<T> {
+            TotalDeposits::<T>::get().saturating_sub(TotalOverdraft::<T>::get())
+        }
+
+        /// Single-call snapshot of an account for wallets, with PAN and Aadhaar masked.
+        pub fn export_account(who: &T::AccountId) -> Option<AccountExportOf<T>> {
+            let acc = BankAccounts::<T>::get(who)?;
+            Some(AccountExport {
+                account_number: acc.account_number,
+                ifsc_code: acc.ifsc_code,
+                bank_name: acc.bank_name,
+                branch_name: acc.branch_name,
+                account_holder: acc.account_holder,
+                holder_pan: acc.holder_pan.as_deref().map(mask_identifier),
+                holder_aadhaar: acc.holder_aadhaar.as_deref().map(mask_identifier),
+                account_type: acc.account_type,
+                opening_date: acc.opening_date,
+                status: acc.status,
+                current_balance: acc.current_balance,
+                overdraft_used: acc.overdraft_used,
+                parent_account: acc.parent_account,
+                child_accounts: acc.child_accounts,
+                recent_transactions: RecentTxns::<T>::get(who).into_inner(),
+            })
+        }
+
+        /// Interest an account would earn over the next `blocks_ahead`, capitalization included,
+        /// without touching storage. Overdrawn accounts hold no positive balance and project zero,
+        /// as do frozen accounts that are not accruing.
+        pub fn projected_interest(who: &T::AccountId, blocks_ahead: T::Moment) -> BalanceOf<T> {
+            let mut acc = match BankAccounts::<T>::get(who) {
+                Some(acc) if acc.overdraft_used.is_zero() && !Self::accrual_suspended(&acc) => acc,
+                _ => return BalanceOf::<T>::zero(),
+            };
+            let now = Self::now();
+            Self::accrue(&mut acc, now);
+            let before = acc.current_balance.saturating_add(acc.accrued_interest);
+            Self::accrue(&mut acc, now.saturating_add(blocks_ahead));
+            acc.current_balance
+                .saturating_add(acc.accrued_interest)
+                .saturating_sub(before)
+        }
+
+        /// Running bank-wide totals and account counts by status.
+        pub fn bank_statistics() -> BankStatistics<BalanceOf<T>> {
+            BankStatistics {
+                total_accounts: TotalAccounts::<T>::get(),
+                total_deposits: TotalDeposits::<T>::get(),
+                operative: StatusCounts::<T>::get(Status::Operative),
+                dormant: StatusCounts::<T>::get(Status::Dormant),
+                closed: StatusCounts::<T>::get(Status::Closed),
+                frozen: StatusCounts::<T>::get(Status::Frozen),
+            }
+        }
+
+        /// An account's metadata entries as plain byte pairs.
+        pub fn metadata_of(who: T::AccountId) -> Vec<(Vec<u8>, Vec<u8>)> {
+            AccountMetadata::<T>::get(&who)
+                .into_iter()
+                .map(|(k, v)| (k.into_inner(), v.into_inner()))
+                .collect()
+        }
+
+        /// Holders of every account carrying the given risk flag.
+        pub fn accounts_with_flag(flag: RiskFlag) -> Vec<T::AccountId> {
+            BankAccounts::<T>::iter()
+                .filter(|(_, acc)| acc.risk_flags.contains(&flag))
+                .map(|(who, _)| who)
+                .collect()
+        }
+    }
+}
+
+/// Replace all but the last `UNMASKED_SUFFIX` characters of an identity number with `X`.
+fn mask_identifier(value: &[u8]) -> Vec<u8> {
+    let visible_from = value.len().saturating_sub(UNMASKED_SUFFIX);
+    value
+        .iter()
+        .enumerate()
+        .map(|(i, c)| if i < visible_from { b'X' } else { *c })
+        .collect()
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait BankingAccountApi<AccountId, Balance, Moment> where
+        AccountId: Codec,
+        Balance: Codec,
+        Moment: Codec,
+    {
+        fn accounts_with_flag(flag: RiskFlag) -> Vec<AccountId>;
+        fn net_position(root: AccountId) -> (Balance, u32);
+        fn export_account(account: AccountId) -> Option<AccountExport<AccountId, Balance, Moment>>;
+        fn projected_interest(account: AccountId, blocks_ahead: Moment) -> Balance;
+        fn bank_statistics() -> BankStatistics<Balance>;
+        fn account_metadata(account: AccountId) -> Vec<(Vec<u8>, Vec<u8>)>;
+    }
+}
+
+/// Transaction extension counting a wrong auth preimage against the signer once its call has
+/// failed with `AuthFailed`. The call's own writes are rolled back with the failure, so the
+/// count is kept here, after dispatch. Auth is always checked against the signer's account.
+/// Runtimes must include it in their signed extensions, or wrong preimages never lock anything.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CountFailedAuth<T: pallet::Config + Send + Sync>(PhantomData<T>);
+
+impl<T: pallet::Config + Send + Sync> CountFailedAuth<T> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: pallet::Config + Send + Sync> Default for CountFailedAuth<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: pallet::Config + Send + Sync> sp_std::fmt::Debug for CountFailedAuth<T> {
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        write!(f, "CountFailedAuth")
+    }
+}
+
+impl<T: pallet::Config + Send + Sync> SignedExtension for CountFailedAuth<T> {
+    const IDENTIFIER: &'static str = "CountFailedAuth";
+    type AccountId = T::AccountId;
+    type Call = <T as frame_system::Config>::RuntimeCall;
+    type AdditionalSigned = ();
+    type Pre = T::AccountId;
+
+    fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+        Ok(())
+    }
+
+    fn pre_dispatch(
+        self,
+        who: &Self::AccountId,
+        _call: &Self::Call,
+        _info: &DispatchInfoOf<Self::Call>,
+        _len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        Ok(who.clone())
+    }
+
+    fn post_dispatch(
+        pre: Option<Self::Pre>,
+        _info: &DispatchInfoOf<Self::Call>,
+        _post_info: &PostDispatchInfoOf<Self::Call>,
+        _len: usize,
+        result: &DispatchResult,
+    ) -> Result<(), TransactionValidityError> {
+        if let (Some(who), Err(error)) = (pre, result) {
+            if *error == DispatchError::from(pallet::Error::<T>::AuthFailed) {
+                pallet::Pallet::<T>::note_failed_auth(&who);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Known banks and their identifier codes, used to cross-check new accounts.
+pub trait BankRegistry {
+    /// IFSC bank prefix and MICR bank code for a bank name, if registered.
+    fn bank_codes(bank_name: &[u8]) -> Option<(Vec<u8>, Vec<u8>)>;
+}
+
+/// No registry: identifiers are only checked for format.
+impl BankRegistry for () {
+    fn bank_codes(_bank_name: &[u8]) -> Option<(Vec<u8>, Vec<u8>)> {
+        None
     }
 }
 
 pub trait WeightInfo {
     fn create_account() -> Weight;
     fn add_sub_account() -> Weight;
+    fn accept_sub_account() -> Weight;
+    fn add_risk_flag() -> Weight;
+    fn remove_risk_flag() -> Weight;
+    fn consolidate() -> Weight;
+    fn deposit() -> Weight;
+    fn withdraw() -> Weight;
+    fn transfer_between() -> Weight;
+    fn clear_velocity_anomaly() -> Weight;
+    fn allocate_to_ledger() -> Weight;
+    fn withdraw_from_ledger() -> Weight;
+    fn reconcile() -> Weight;
+    fn transfer_with_sig() -> Weight;
+    fn add_whitelist_entry() -> Weight;
+    fn remove_whitelist_entry() -> Weight;
+    fn set_whitelist_enabled() -> Weight;
+    fn set_high_limit_override() -> Weight;
+    fn set_inbound_only() -> Weight;
+    fn clear_inbound_only() -> Weight;
+    fn set_notification_preferences() -> Weight;
+    fn set_spending_limit() -> Weight;
+    fn set_auth_secret() -> Weight;
+    fn reset_auth_attempts() -> Weight;
+    fn set_accrue_while_frozen() -> Weight;
+    fn set_overdraft_alert() -> Weight;
+    fn set_account_metadata() -> Weight;
+    fn remove_account_metadata_key() -> Weight;
+    fn set_capitalization() -> Weight;
+    fn accrue_interest() -> Weight;
+    fn heartbeat() -> Weight;
+    fn set_nominee() -> Weight;
+    fn claim_succession() -> Weight;
+    fn detach_sub_account() -> Weight;
+    fn request_closure() -> Weight;
+    fn finalize_closure() -> Weight;
+    fn seize_account() -> Weight;
+    fn process_task() -> Weight;
 }
 
 // 🧪 Default weights (mock); replace with benchmarked weights in production
@@ -0,0 +1,312 @@
+use crate as banking_account;
+use crate::{BankRegistry, DenominationPolicy, WeightInfo};
+use frame_support::{
+    parameter_types,
+    traits::{ConstU32, ConstU64, OnIdle, OnInitialize},
+    weights::Weight,
+};
+use frame_system::EnsureRoot;
+use sp_core::{sr25519, Pair, H256};
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    AccountId32, Permill,
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = AccountId32;
+
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        Banking: banking_account::pallet::{Pallet, Call, Storage, Event<T>},
+    }
+);
+
+/// Seed of the treasury that funds interest and welcome credits
+pub const TREASURY: u8 = 100;
+
+/// Free balance each funded account starts with
+pub const ENDOWMENT: u64 = 1_000_000;
+
+/// Bank name known to `MockRegistry`, with its IFSC prefix and MICR bank code
+pub const REGISTERED_BANK: &[u8] = b"STATE BANK";
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const ExistentialDeposit: u64 = 1;
+
+    pub const CurrentMinimumBalance: u64 = 500;
+    pub const CurrentOverdraftLimit: u64 = 1_000;
+    pub const VelocityWindow: u64 = 10;
+    pub const ReconciliationTolerance: u64 = 0;
+    pub const BlocksPerYear: u64 = 1_200;
+    pub Treasury: AccountId = account(TREASURY);
+    pub const InactivityForSuccession: u64 = 100;
+    pub const SuccessionDelay: u64 = 10;
+    pub const ClosureGracePeriod: u64 = 10;
+    pub const BlocksPerDay: u64 = 10;
+    pub const DormancyWarningPeriod: u64 = 10;
+
+    // Switched per test through `ExtBuilder`
+    pub static DailyLimit: u64 = 100_000;
+    pub static HighRiskDailyLimit: u64 = 1_000;
+    pub static MinimumBalance: u64 = 100;
+    pub static VelocityThreshold: u32 = 100;
+    pub static FreezeOnVelocityAnomaly: bool = false;
+    pub static MinDenomination: u64 = 0;
+    pub static Denomination: DenominationPolicy = DenominationPolicy::Reject;
+    pub static MaxSingleTxn: u64 = 100_000;
+    pub static InterestRate: Permill = Permill::zero();
+    pub static InterestSweepInterval: u64 = 0;
+    pub static PairTransferWindow: u64 = 0;
+    pub static MaxPairTransferPerWindow: u64 = u64::MAX;
+    pub static WelcomeCredit: u64 = 0;
+    pub static MaxHierarchyDepth: u32 = 4;
+    pub static LedgerExpirySweepInterval: u64 = 0;
+    pub static DormancyPeriod: u64 = 0;
+    pub static DormancySweepInterval: u64 = 0;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type DustRemoval = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type HoldIdentifier = ();
+    type MaxHolds = ();
+}
+
+/// Knows `REGISTERED_BANK` as IFSC prefix `SBIN` and MICR bank code `002`
+pub struct MockRegistry;
+impl BankRegistry for MockRegistry {
+    fn bank_codes(bank_name: &[u8]) -> Option<(Vec<u8>, Vec<u8>)> {
+        if bank_name == REGISTERED_BANK {
+            Some((b"SBIN".to_vec(), b"002".to_vec()))
+        } else {
+            None
+        }
+    }
+}
+
+/// Flat weights; tests only care that each task costs something
+pub struct TestWeights;
+impl WeightInfo for TestWeights {
+    fn create_account() -> Weight { Weight::from_parts(10_000, 0) }
+    fn add_sub_account() -> Weight { Weight::from_parts(10_000, 0) }
+    fn accept_sub_account() -> Weight { Weight::from_parts(10_000, 0) }
+    fn add_risk_flag() -> Weight { Weight::from_parts(10_000, 0) }
+    fn remove_risk_flag() -> Weight { Weight::from_parts(10_000, 0) }
+    fn consolidate() -> Weight { Weight::from_parts(10_000, 0) }
+    fn deposit() -> Weight { Weight::from_parts(10_000, 0) }
+    fn withdraw() -> Weight { Weight::from_parts(10_000, 0) }
+    fn transfer_between() -> Weight { Weight::from_parts(10_000, 0) }
+    fn clear_velocity_anomaly() -> Weight { Weight::from_parts(10_000, 0) }
+    fn allocate_to_ledger() -> Weight { Weight::from_parts(10_000, 0) }
+    fn withdraw_from_ledger() -> Weight { Weight::from_parts(10_000, 0) }
+    fn reconcile() -> Weight { Weight::from_parts(10_000, 0) }
+    fn transfer_with_sig() -> Weight { Weight::from_parts(10_000, 0) }
+    fn add_whitelist_entry() -> Weight { Weight::from_parts(10_000, 0) }
+    fn remove_whitelist_entry() -> Weight { Weight::from_parts(10_000, 0) }
+    fn set_whitelist_enabled() -> Weight { Weight::from_parts(10_000, 0) }
+    fn set_high_limit_override() -> Weight { Weight::from_parts(10_000, 0) }
+    fn set_inbound_only() -> Weight { Weight::from_parts(10_000, 0) }
+    fn clear_inbound_only() -> Weight { Weight::from_parts(10_000, 0) }
+    fn set_notification_preferences() -> Weight { Weight::from_parts(10_000, 0) }
+    fn set_spending_limit() -> Weight { Weight::from_parts(10_000, 0) }
+    fn set_auth_secret() -> Weight { Weight::from_parts(10_000, 0) }
+    fn reset_auth_attempts() -> Weight { Weight::from_parts(10_000, 0) }
+    fn set_accrue_while_frozen() -> Weight { Weight::from_parts(10_000, 0) }
+    fn set_overdraft_alert() -> Weight { Weight::from_parts(10_000, 0) }
+    fn set_account_metadata() -> Weight { Weight::from_parts(10_000, 0) }
+    fn remove_account_metadata_key() -> Weight { Weight::from_parts(10_000, 0) }
+    fn set_capitalization() -> Weight { Weight::from_parts(10_000, 0) }
+    fn accrue_interest() -> Weight { Weight::from_parts(10_000, 0) }
+    fn heartbeat() -> Weight { Weight::from_parts(10_000, 0) }
+    fn set_nominee() -> Weight { Weight::from_parts(10_000, 0) }
+    fn claim_succession() -> Weight { Weight::from_parts(10_000, 0) }
+    fn detach_sub_account() -> Weight { Weight::from_parts(10_000, 0) }
+    fn request_closure() -> Weight { Weight::from_parts(10_000, 0) }
+    fn finalize_closure() -> Weight { Weight::from_parts(10_000, 0) }
+    fn seize_account() -> Weight { Weight::from_parts(10_000, 0) }
+    fn process_task() -> Weight { Weight::from_parts(10_000, 0) }
+}
+
+impl banking_account::pallet::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Moment = u64;
+    type WeightInfo = TestWeights;
+    type AdminOrigin = EnsureRoot<AccountId>;
+    type MaxFlags = ConstU32<2>;
+    type DailyLimit = DailyLimit;
+    type HighRiskDailyLimit = HighRiskDailyLimit;
+    type MinimumBalance = MinimumBalance;
+    type CurrentMinimumBalance = CurrentMinimumBalance;
+    type CurrentOverdraftLimit = CurrentOverdraftLimit;
+    type VelocityWindow = VelocityWindow;
+    type VelocityThreshold = VelocityThreshold;
+    type FreezeOnVelocityAnomaly = FreezeOnVelocityAnomaly;
+    type MaxLedgers = ConstU32<2>;
+    type MaxLabel = ConstU32<8>;
+    type ReconciliationTolerance = ReconciliationTolerance;
+    type MinDenomination = MinDenomination;
+    type DenominationPolicy = Denomination;
+    type MaxWhitelist = ConstU32<2>;
+    type MaxSingleTxn = MaxSingleTxn;
+    type InterestRate = InterestRate;
+    type BlocksPerYear = BlocksPerYear;
+    type Treasury = Treasury;
+    type InactivityForSuccession = InactivityForSuccession;
+    type SuccessionDelay = SuccessionDelay;
+    type InterestSweepInterval = InterestSweepInterval;
+    type MaxRecentTxns = ConstU32<3>;
+    type PairTransferWindow = PairTransferWindow;
+    type MaxPairTransferPerWindow = MaxPairTransferPerWindow;
+    type ClosureGracePeriod = ClosureGracePeriod;
+    type WelcomeCredit = WelcomeCredit;
+    type BankRegistry = MockRegistry;
+    type MaxHierarchyDepth = MaxHierarchyDepth;
+    type BlocksPerDay = BlocksPerDay;
+    type MaxAuthAttempts = ConstU32<3>;
+    type MaxMetadataKey = ConstU32<8>;
+    type MaxMetadataValue = ConstU32<16>;
+    type MaxMetadataEntries = ConstU32<2>;
+    type LedgerExpirySweepInterval = LedgerExpirySweepInterval;
+    type DormancyPeriod = DormancyPeriod;
+    type DormancyWarningPeriod = DormancyWarningPeriod;
+    type DormancySweepInterval = DormancySweepInterval;
+}
+
+/// Keypair behind the account with the given seed
+pub fn pair(seed: u8) -> sr25519::Pair {
+    sr25519::Pair::from_seed(&[seed; 32])
+}
+
+/// Account id of the keypair with the given seed
+pub fn account(seed: u8) -> AccountId {
+    pair(seed).public().into()
+}
+
+/// Test externalities at block 1, with accounts 1..=10 and the treasury funded
+pub struct ExtBuilder {
+    setup: Vec<Box<dyn FnOnce()>>,
+}
+
+impl Default for ExtBuilder {
+    fn default() -> Self {
+        ExtBuilder { setup: Vec::new() }
+    }
+}
+
+impl ExtBuilder {
+    /// Apply a config switch before the test body runs
+    pub fn with(mut self, setup: impl FnOnce() + 'static) -> Self {
+        self.setup.push(Box::new(setup));
+        self
+    }
+
+    pub fn build(self) -> sp_io::TestExternalities {
+        let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+        let mut balances: Vec<(AccountId, u64)> = (1..=10).map(|seed| (account(seed), ENDOWMENT)).collect();
+        balances.push((account(TREASURY), ENDOWMENT));
+        pallet_balances::GenesisConfig::<Test> { balances }
+            .assimilate_storage(&mut storage)
+            .unwrap();
+
+        let mut ext = sp_io::TestExternalities::new(storage);
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            for setup in self.setup {
+                setup();
+            }
+        });
+        ext
+    }
+}
+
+/// Run the idle and initialize hooks of each block up to `n`, with unlimited idle weight
+pub fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        let now = System::block_number();
+        Banking::on_idle(now, Weight::MAX);
+        System::set_block_number(now + 1);
+        Banking::on_initialize(now + 1);
+    }
+}
+
+/// Pallet events emitted so far, oldest first
+pub fn banking_events() -> Vec<banking_account::pallet::Event<Test>> {
+    System::events()
+        .into_iter()
+        .filter_map(|record| match record.event {
+            RuntimeEvent::Banking(inner) => Some(inner),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Open a savings account for `seed` with `balance`, using placeholder identifiers
+pub fn open(seed: u8, balance: u64) {
+    Banking::create_account(
+        RuntimeOrigin::signed(account(seed)),
+        vec![seed],
+        b"SBIN0001234".to_vec(),
+        b"TEST BANK".to_vec(),
+        b"MAIN".to_vec(),
+        b"1 HIGH STREET".to_vec(),
+        None,
+        Some(b"ABCDE1234F".to_vec()),
+        None,
+        None,
+        b"SAVINGS".to_vec(),
+        balance,
+    )
+    .unwrap();
+}
+
+/// Ledger balance of the account held by `seed`
+pub fn balance(seed: u8) -> u64 {
+    Banking::bank_accounts(account(seed)).expect("account exists").current_balance
+}
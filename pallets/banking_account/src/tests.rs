@@ -0,0 +1,1360 @@
+use crate::mock::*;
+use crate::pallet::{BankAccounts, Error, Event, TotalDeposits};
+use crate::{
+    AccountTemplate, CapFrequency, CountFailedAuth, DenominationPolicy, LimitPeriod, NotificationKind, RiskFlag,
+    Status, SweepKind, TxnKind,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::{DispatchResultWithPostInfo, GetDispatchInfo},
+    traits::Hooks,
+    weights::Weight,
+    BoundedVec,
+};
+use sp_core::{Pair, H256};
+use sp_runtime::{
+    traits::{BadOrigin, Dispatchable, SignedExtension},
+    Permill,
+};
+
+/// Whether `value` survives an encode/decode round trip byte-for-byte, so nodes
+/// exchanging it (e.g. a `BankingAccount`) agree on its encoding.
+fn encoding_is_stable<V: Encode + Decode>(value: &V) -> bool {
+    let encoded = value.encode();
+    V::decode(&mut &encoded[..])
+        .map(|decoded| decoded.encode() == encoded)
+        .unwrap_or(false)
+}
+
+/// Propose `child` under `parent` and accept it as the child
+fn link(parent: u8, child: u8) {
+    assert_ok!(Banking::add_sub_account(
+        RuntimeOrigin::signed(account(parent)),
+        account(parent),
+        account(child),
+        None,
+        None
+    ));
+    assert_ok!(Banking::accept_sub_account(RuntimeOrigin::signed(account(child)), account(parent)));
+}
+
+fn transfer(from: u8, to: u8, amount: u64) -> sp_runtime::DispatchResult {
+    Banking::transfer_between(RuntimeOrigin::signed(account(from)), account(to), amount, None)
+}
+
+/// Dispatch `call` signed by `seed` between the `CountFailedAuth` hooks, as a runtime would
+fn dispatch_signed(seed: u8, call: RuntimeCall) -> DispatchResultWithPostInfo {
+    let who = account(seed);
+    let info = call.get_dispatch_info();
+    let pre = CountFailedAuth::<Test>::new().pre_dispatch(&who, &call, &info, 0).unwrap();
+    let result = call.dispatch(RuntimeOrigin::signed(who));
+    let post_info = result.unwrap_or_else(|e| e.post_info);
+    CountFailedAuth::<Test>::post_dispatch(Some(pre), &info, &post_info, 0, &result.map(|_| ()).map_err(|e| e.error))
+        .unwrap();
+    result
+}
+
+fn withdraw_call(amount: u64, auth: &[u8]) -> RuntimeCall {
+    RuntimeCall::Banking(crate::pallet::Call::withdraw { amount, auth: Some(auth.to_vec()) })
+}
+
+fn failed_auth_attempts(seed: u8) -> u32 {
+    Banking::bank_accounts(account(seed)).expect("account exists").failed_auth_attempts
+}
+
+/// Relay a transfer from `from`, signed by `signer` for the chain with `genesis`
+fn relayed_transfer(
+    signer: u8,
+    genesis: H256,
+    from: u8,
+    to: u8,
+    amount: u64,
+    nonce: u64,
+) -> sp_runtime::DispatchResult {
+    let message = (b"banking/transfer", genesis, account(to), amount, nonce).encode();
+    Banking::transfer_with_sig(
+        RuntimeOrigin::signed(account(9)),
+        account(from),
+        account(to),
+        amount,
+        nonce,
+        pair(signer).sign(&message),
+    )
+}
+
+/// Relay a transfer signed by `from` itself for this chain
+fn signed_transfer(from: u8, to: u8, amount: u64, nonce: u64) -> sp_runtime::DispatchResult {
+    relayed_transfer(from, System::block_hash(0), from, to, amount, nonce)
+}
+
+fn parent_of(seed: u8) -> Option<AccountId> {
+    Banking::bank_accounts(account(seed)).expect("account exists").parent_account
+}
+
+fn risk_flags(seed: u8) -> Vec<RiskFlag> {
+    Banking::bank_accounts(account(seed)).expect("account exists").risk_flags.into_inner()
+}
+
+/// Open an account for `seed` from `template`, with placeholder identifiers
+fn open_from_template(seed: u8, template: AccountTemplate, balance: u64) {
+    assert_ok!(Banking::create_from_template(
+        RuntimeOrigin::signed(account(seed)),
+        template,
+        vec![seed],
+        b"SBIN0001234".to_vec(),
+        b"TEST BANK".to_vec(),
+        b"MAIN".to_vec(),
+        b"1 HIGH STREET".to_vec(),
+        None,
+        Some(b"ABCDE1234F".to_vec()),
+        None,
+        None,
+        balance
+    ));
+}
+
+fn label(name: &[u8]) -> BoundedVec<u8, <Test as crate::pallet::Config>::MaxLabel> {
+    name.to_vec().try_into().expect("label fits")
+}
+
+/// Open an account for `seed` through `create_account_full` with the given bank identifiers
+fn open_full(seed: u8, ifsc: &[u8], micr: &[u8], bank_name: &[u8]) -> sp_runtime::DispatchResult {
+    Banking::create_account_full(
+        RuntimeOrigin::signed(account(seed)),
+        vec![seed],
+        ifsc.to_vec(),
+        micr.to_vec(),
+        bank_name.to_vec(),
+        b"MAIN".to_vec(),
+        b"1 HIGH STREET".to_vec(),
+        None,
+        None,
+        None,
+        None,
+        b"SAVINGS".to_vec(),
+        1_000,
+    )
+}
+
+/// Run `setup` and commit it, then run `call`, returning the size of the proof of everything
+/// `call` read and the keys it wrote under this pallet's prefix
+fn storage_accesses(setup: impl FnOnce(), call: impl FnOnce()) -> (usize, Vec<Vec<u8>>) {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(setup);
+    ext.commit_all().unwrap();
+    let ((), proof) = ext.execute_and_prove(call);
+    let prefix = sp_io::hashing::twox_128(b"Banking");
+    let written = ext.overlay.changes().map(|(key, _)| key.clone()).filter(|key| key.starts_with(&prefix));
+    (proof.encoded_size(), written.collect())
+}
+
+#[test]
+fn risk_flags_are_admin_only_unique_and_bounded() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        assert_noop!(
+            Banking::add_risk_flag(RuntimeOrigin::signed(account(1)), account(1), RiskFlag::Exempt),
+            BadOrigin
+        );
+
+        assert_ok!(Banking::add_risk_flag(RuntimeOrigin::root(), account(1), RiskFlag::Pep));
+        assert_noop!(
+            Banking::add_risk_flag(RuntimeOrigin::root(), account(1), RiskFlag::Pep),
+            Error::<Test>::RiskFlagAlreadySet
+        );
+        assert_ok!(Banking::add_risk_flag(RuntimeOrigin::root(), account(1), RiskFlag::HighRisk));
+        assert_noop!(
+            Banking::add_risk_flag(RuntimeOrigin::root(), account(1), RiskFlag::Exempt),
+            Error::<Test>::TooManyRiskFlags
+        );
+        assert_eq!(risk_flags(1), vec![RiskFlag::Pep, RiskFlag::HighRisk]);
+        assert_eq!(banking_events().last(), Some(&Event::RiskFlagAdded(account(1), RiskFlag::HighRisk)));
+    });
+}
+
+#[test]
+fn accounts_are_listed_by_flag_until_it_is_removed() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        open(2, 1_000);
+        assert_ok!(Banking::add_risk_flag(RuntimeOrigin::root(), account(1), RiskFlag::Pep));
+        assert_ok!(Banking::add_risk_flag(RuntimeOrigin::root(), account(2), RiskFlag::HighRisk));
+
+        assert_eq!(Banking::accounts_with_flag(RiskFlag::Pep), vec![account(1)]);
+        assert!(Banking::accounts_with_flag(RiskFlag::Exempt).is_empty());
+
+        assert_ok!(Banking::remove_risk_flag(RuntimeOrigin::root(), account(1), RiskFlag::Pep));
+        assert_noop!(
+            Banking::remove_risk_flag(RuntimeOrigin::root(), account(1), RiskFlag::Pep),
+            Error::<Test>::RiskFlagNotSet
+        );
+        assert!(Banking::accounts_with_flag(RiskFlag::Pep).is_empty());
+    });
+}
+
+#[test]
+fn high_risk_accounts_get_the_tighter_daily_limit_while_flagged() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 10_000);
+        let withdraw = || Banking::withdraw(RuntimeOrigin::signed(account(1)), 1_500, None);
+        assert_ok!(withdraw());
+
+        assert_ok!(Banking::add_risk_flag(RuntimeOrigin::root(), account(1), RiskFlag::HighRisk));
+        assert_eq!(Banking::daily_limit(&Banking::bank_accounts(account(1)).unwrap()), HighRiskDailyLimit::get());
+        assert_noop!(withdraw(), Error::<Test>::SpendingLimitExceeded);
+
+        assert_ok!(Banking::remove_risk_flag(RuntimeOrigin::root(), account(1), RiskFlag::HighRisk));
+        assert_ok!(withdraw());
+        assert_eq!(balance(1), 7_000);
+    });
+}
+
+#[test]
+fn account_encoding_is_stable_at_edge_values() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        let mut acc = Banking::bank_accounts(account(1)).expect("account exists");
+        assert!(encoding_is_stable(&acc));
+
+        acc.current_balance = 0;
+        acc.accrued_interest = 0;
+        assert!(encoding_is_stable(&acc));
+
+        acc.current_balance = u64::MAX;
+        acc.overdraft_limit = Some(u64::MAX);
+        acc.low_balance_threshold = u64::MAX;
+        acc.last_accrual = u64::MAX;
+        acc.failed_auth_attempts = u32::MAX;
+        assert!(encoding_is_stable(&acc));
+
+        // Overdrawn: balances are unsigned, so the negative position is the drawn overdraft
+        acc.current_balance = 0;
+        acc.overdraft_limit = Some(1_000);
+        acc.overdraft_used = 1_000;
+        assert!(encoding_is_stable(&acc));
+    });
+}
+
+#[test]
+fn sub_accounts_are_linked_and_funded_only_once_the_child_accepts() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        open(2, 1_000);
+        assert_ok!(Banking::add_sub_account(
+            RuntimeOrigin::signed(account(1)),
+            account(1),
+            account(2),
+            Some(300),
+            None
+        ));
+        assert_eq!((balance(1), balance(2)), (1_000, 1_000));
+        assert_eq!(parent_of(2), None);
+
+        assert_noop!(
+            Banking::accept_sub_account(RuntimeOrigin::signed(account(3)), account(1)),
+            Error::<Test>::NoPendingSubAccount
+        );
+        assert_ok!(Banking::accept_sub_account(RuntimeOrigin::signed(account(2)), account(1)));
+        assert_eq!((balance(1), balance(2)), (700, 1_300));
+        assert_eq!(parent_of(2), Some(account(1)));
+        assert_eq!(Banking::bank_accounts(account(1)).unwrap().child_accounts, vec![account(2)]);
+        assert_eq!(banking_events().last(), Some(&Event::SubAccountAdded(account(1), account(2))));
+        assert_noop!(
+            Banking::accept_sub_account(RuntimeOrigin::signed(account(2)), account(1)),
+            Error::<Test>::NoPendingSubAccount
+        );
+    });
+}
+
+#[test]
+fn a_child_with_a_parent_cannot_be_claimed_again() {
+    ExtBuilder::default().build().execute_with(|| {
+        for seed in 1..=4 {
+            open(seed, 1_000);
+        }
+        link(1, 2);
+        assert_noop!(
+            Banking::add_sub_account(RuntimeOrigin::signed(account(3)), account(3), account(2), None, None),
+            Error::<Test>::AlreadyHasParent
+        );
+
+        // A proposal still pending when the child joins elsewhere cannot be accepted later
+        assert_ok!(Banking::add_sub_account(
+            RuntimeOrigin::signed(account(3)),
+            account(3),
+            account(4),
+            None,
+            None
+        ));
+        link(1, 4);
+        assert_noop!(
+            Banking::accept_sub_account(RuntimeOrigin::signed(account(4)), account(3)),
+            Error::<Test>::AlreadyHasParent
+        );
+    });
+}
+
+#[test]
+fn consolidate_sweeps_only_children_that_accepted() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        open(2, 1_000);
+        open(3, 1_000);
+        link(1, 2);
+        // A child list entry the account never accepted is not followed
+        BankAccounts::<Test>::mutate(account(1), |acc| acc.as_mut().unwrap().child_accounts.push(account(3)));
+
+        assert_ok!(Banking::consolidate(RuntimeOrigin::signed(account(1)), account(1)));
+        assert_eq!(balance(1), 1_900);
+        assert_eq!(balance(2), 100);
+        assert_eq!(balance(3), 1_000);
+    });
+}
+
+#[test]
+fn a_child_can_leave_its_parent() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        open(2, 1_000);
+        open(3, 1_000);
+        link(1, 2);
+        assert_noop!(
+            Banking::detach_sub_account(RuntimeOrigin::signed(account(3)), account(2)),
+            Error::<Test>::NotParentAccount
+        );
+        assert_ok!(Banking::detach_sub_account(RuntimeOrigin::signed(account(2)), account(2)));
+        assert_eq!(parent_of(2), None);
+
+        assert_ok!(Banking::consolidate(RuntimeOrigin::signed(account(1)), account(1)));
+        assert_eq!(balance(2), 1_000);
+    });
+}
+
+#[test]
+fn shortening_the_capitalization_period_pays_interest_once() {
+    ExtBuilder::default()
+        .with(|| InterestRate::set(Permill::from_percent(12)))
+        .build()
+        .execute_with(|| {
+            open(1, 100_000);
+            open(2, 100_000);
+            run_to_block(500);
+            assert_ok!(Banking::accrue_interest(RuntimeOrigin::signed(account(1)), account(1)));
+            assert_ok!(Banking::set_capitalization(RuntimeOrigin::signed(account(1)), CapFrequency::Monthly));
+
+            run_to_block(600);
+            for seed in [1, 2] {
+                assert_ok!(Banking::accrue_interest(RuntimeOrigin::signed(account(seed)), account(seed)));
+            }
+            let switched = Banking::bank_accounts(account(1)).unwrap();
+            let annual = Banking::bank_accounts(account(2)).unwrap();
+            // 10 a block at 12% of 100_000 over 1_200 blocks, for the 599 blocks since opening
+            assert_eq!((switched.current_balance, switched.accrued_interest), (105_990, 0));
+            assert_eq!(annual.current_balance + annual.accrued_interest, switched.current_balance);
+        });
+}
+
+#[test]
+fn only_outflows_count_towards_velocity() {
+    ExtBuilder::default()
+        .with(|| {
+            VelocityThreshold::set(2);
+            FreezeOnVelocityAnomaly::set(true);
+        })
+        .build()
+        .execute_with(|| {
+            open(1, 1_000);
+            open(2, 1_000);
+            // Three credits from a stranger leave the recipient alone
+            for _ in 0..3 {
+                assert_ok!(transfer(2, 1, 10));
+            }
+            let recipient = Banking::bank_accounts(account(1)).unwrap();
+            assert!(!recipient.velocity_anomaly);
+            assert_eq!(recipient.status, Status::Operative);
+            assert_eq!(Banking::txn_velocity(account(1)), None);
+
+            // The sender's own three outflows trip it
+            let sender = Banking::bank_accounts(account(2)).unwrap();
+            assert!(sender.velocity_anomaly);
+            assert_eq!(sender.status, Status::Frozen);
+            assert!(banking_events().contains(&Event::VelocityAnomalyDetected(account(2), 3)));
+        });
+}
+
+#[test]
+fn a_wrong_preimage_fails_the_call_and_is_still_counted() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        let secret = sp_io::hashing::blake2_256(b"open sesame");
+        assert_ok!(Banking::set_auth_secret(RuntimeOrigin::signed(account(1)), Some(secret), None));
+
+        let result = dispatch_signed(1, withdraw_call(100, b"guess"));
+        assert_eq!(result.map_err(|e| e.error), Err(Error::<Test>::AuthFailed.into()));
+        assert_eq!(balance(1), 1_000);
+        assert_eq!(failed_auth_attempts(1), 1);
+        assert_eq!(banking_events().last(), Some(&Event::AuthFailed(account(1), 1)));
+
+        assert_ok!(dispatch_signed(1, withdraw_call(100, b"open sesame")));
+        assert_eq!(balance(1), 900);
+        assert_eq!(failed_auth_attempts(1), 0);
+
+        // Three wrong guesses lock the account, even against the right preimage
+        for _ in 0..3 {
+            assert!(dispatch_signed(1, withdraw_call(100, b"guess")).is_err());
+        }
+        let result = dispatch_signed(1, withdraw_call(100, b"open sesame"));
+        assert_eq!(result.map_err(|e| e.error), Err(Error::<Test>::AuthLocked.into()));
+        assert_eq!(failed_auth_attempts(1), 3);
+    });
+}
+
+#[test]
+fn total_deposits_is_the_sum_of_every_balance() {
+    let held = || BankAccounts::<Test>::iter_values().map(|acc| acc.current_balance).sum::<u64>();
+    ExtBuilder::default()
+        .with(|| {
+            WelcomeCredit::set(50);
+            InterestRate::set(Permill::from_percent(12));
+        })
+        .build()
+        .execute_with(|| {
+            open(1, 1_000);
+            open(2, 1_000);
+            assert_eq!(Banking::total_deposits(), 2_100);
+
+            // Overdraft draws, repayments and transfers all move the total with the ledger
+            BankAccounts::<Test>::mutate(account(1), |acc| acc.as_mut().unwrap().overdraft_limit = Some(500));
+            assert_ok!(Banking::withdraw(RuntimeOrigin::signed(account(1)), 1_200, None));
+            assert_ok!(transfer(2, 1, 300));
+            assert_ok!(Banking::deposit(RuntimeOrigin::signed(account(2)), 200));
+            assert_eq!(Banking::total_deposits(), held());
+            assert_eq!(
+                Banking::total_overdraft(),
+                BankAccounts::<Test>::iter_values().map(|acc| acc.overdraft_used).sum::<u64>()
+            );
+
+            // Capitalized interest is counted once it lands in the balance
+            assert_ok!(Banking::set_capitalization(RuntimeOrigin::signed(account(2)), CapFrequency::Monthly));
+            run_to_block(200);
+            let before = Banking::total_deposits();
+            assert_ok!(Banking::accrue_interest(RuntimeOrigin::signed(account(2)), account(2)));
+            assert!(Banking::total_deposits() > before);
+            assert_eq!(Banking::total_deposits(), held());
+
+            assert_ok!(Banking::request_closure(RuntimeOrigin::signed(account(1))));
+            run_to_block(220);
+            assert_ok!(Banking::finalize_closure(RuntimeOrigin::signed(account(1)), None));
+            assert_eq!(Banking::total_deposits(), held());
+        });
+}
+
+#[test]
+fn account_updates_touch_total_deposits_only_when_the_balance_moves() {
+    let opened = || open(1, 1_000);
+    let heartbeat = || assert_ok!(Banking::heartbeat(RuntimeOrigin::signed(account(1))));
+
+    // A heartbeat writes the account record alone, and reading the total as well grows the
+    // proof, so the heartbeat itself never read it
+    let (read, written) = storage_accesses(opened, heartbeat);
+    assert_eq!(written, vec![BankAccounts::<Test>::hashed_key_for(account(1))]);
+    let (read_with_total, _) = storage_accesses(opened, || {
+        heartbeat();
+        TotalDeposits::<Test>::get();
+    });
+    assert!(read_with_total > read);
+
+    // A deposit moves the balance, and the total with it
+    let (_, written) = storage_accesses(opened, || {
+        assert_ok!(Banking::deposit(RuntimeOrigin::signed(account(1)), 100))
+    });
+    assert!(written.contains(&BankAccounts::<Test>::hashed_key_for(account(1))));
+    assert!(written.contains(&TotalDeposits::<Test>::hashed_key().to_vec()));
+}
+
+#[test]
+fn bank_statistics_match_a_recount_of_every_account() {
+    let recount = |status: Status| BankAccounts::<Test>::iter_values().filter(|acc| acc.status == status).count() as u32;
+    ExtBuilder::default()
+        .with(|| {
+            VelocityThreshold::set(0);
+            FreezeOnVelocityAnomaly::set(true);
+            DormancyPeriod::set(50);
+            DormancySweepInterval::set(5);
+        })
+        .build()
+        .execute_with(|| {
+            for seed in 1..=4 {
+                open(seed, 1_000);
+            }
+            // 2 is frozen by its first outflow and 3 is closed, both before the sweep runs
+            assert_ok!(transfer(2, 4, 100));
+            assert_ok!(Banking::request_closure(RuntimeOrigin::signed(account(3))));
+            assert_ok!(Banking::finalize_closure(RuntimeOrigin::signed(account(3)), None));
+
+            // 1 and 4 go dormant with no activity; only 4 comes back
+            run_to_block(56);
+            assert_ok!(Banking::heartbeat(RuntimeOrigin::signed(account(4))));
+
+            let stats = Banking::bank_statistics();
+            assert_eq!((stats.operative, stats.dormant, stats.closed, stats.frozen), (1, 1, 1, 1));
+            assert_eq!(stats.total_accounts, 4);
+            assert_eq!(stats.total_accounts, BankAccounts::<Test>::iter().count() as u32);
+            assert_eq!(
+                stats.total_deposits,
+                BankAccounts::<Test>::iter_values().map(|acc| acc.current_balance).sum::<u64>()
+            );
+            assert_eq!(
+                (stats.operative, stats.dormant, stats.closed, stats.frozen),
+                (recount(Status::Operative), recount(Status::Dormant), recount(Status::Closed), recount(Status::Frozen))
+            );
+        });
+}
+
+#[test]
+fn a_sweep_is_not_queued_again_while_one_is_pending() {
+    ExtBuilder::default()
+        .with(|| InterestSweepInterval::set(1))
+        .build()
+        .execute_with(|| {
+            for seed in 1..=3 {
+                open(seed, 1_000);
+            }
+            // Blocks with no idle weight each hit the interval, but only one pass is queued
+            for n in 2..=5 {
+                System::set_block_number(n);
+                Banking::on_initialize(n);
+            }
+            assert_eq!(Banking::task_tail() - Banking::task_head(), 1);
+            assert!(Banking::sweep_pending(SweepKind::Interest));
+
+            // One step per account, plus the step that finds the end and clears the marker
+            Banking::on_idle(5, Weight::MAX);
+            assert_eq!(Banking::task_head(), Banking::task_tail());
+            assert!(!Banking::sweep_pending(SweepKind::Interest));
+
+            System::set_block_number(6);
+            Banking::on_initialize(6);
+            assert_eq!(Banking::task_tail() - Banking::task_head(), 1);
+        });
+}
+
+#[test]
+fn signed_transfers_need_the_holders_signature_for_this_chain() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        open(2, 1_000);
+        let genesis = System::block_hash(0);
+
+        assert_noop!(relayed_transfer(3, genesis, 1, 2, 100, 0), Error::<Test>::InvalidSignature);
+        assert_noop!(relayed_transfer(1, H256::repeat_byte(1), 1, 2, 100, 0), Error::<Test>::InvalidSignature);
+
+        assert_ok!(signed_transfer(1, 2, 100, 0));
+        assert_eq!((balance(1), balance(2)), (900, 1_100));
+        assert_eq!(Banking::signature_nonce(account(1)), 1);
+        assert!(banking_events().contains(&Event::SignedTransferExecuted(account(1), account(2), 100, 0)));
+
+        // The same signed message cannot be played twice
+        assert_noop!(signed_transfer(1, 2, 100, 0), Error::<Test>::InvalidNonce);
+        assert_ok!(signed_transfer(1, 2, 100, 1));
+    });
+}
+
+#[test]
+fn signed_transfers_are_never_rounded() {
+    ExtBuilder::default()
+        .with(|| {
+            MinDenomination::set(100);
+            Denomination::set(DenominationPolicy::RoundDown);
+        })
+        .build()
+        .execute_with(|| {
+            open(1, 1_000);
+            open(2, 1_000);
+            assert_noop!(signed_transfer(1, 2, 150, 0), Error::<Test>::InvalidDenomination);
+            assert_ok!(signed_transfer(1, 2, 200, 0));
+            assert_eq!(balance(1), 800);
+        });
+}
+
+#[test]
+fn signed_transfers_need_no_preimage_but_respect_the_auth_lock() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        open(2, 1_000);
+        let secret = sp_io::hashing::blake2_256(b"open sesame");
+        assert_ok!(Banking::set_auth_secret(RuntimeOrigin::signed(account(1)), Some(secret), None));
+        assert_ok!(signed_transfer(1, 2, 100, 0));
+
+        BankAccounts::<Test>::mutate(account(1), |acc| acc.as_mut().unwrap().failed_auth_attempts = 3);
+        assert_noop!(signed_transfer(1, 2, 100, 1), Error::<Test>::AuthLocked);
+    });
+}
+
+#[test]
+fn templates_preset_the_account_type_limits_and_services() {
+    ExtBuilder::default().build().execute_with(|| {
+        open_from_template(1, AccountTemplate::Current, 1_000);
+        open_from_template(2, AccountTemplate::SalaryAccount, 1_000);
+        // Liquidity from another holder, so overdraft payouts can leave the pallet
+        open(3, 5_000);
+
+        let current = Banking::bank_accounts(account(1)).unwrap();
+        assert_eq!(current.account_type, b"CURRENT".to_vec());
+        assert_eq!((current.minimum_balance, current.overdraft_limit), (500, Some(1_000)));
+        assert!(current.has_cheque_book && current.has_atm_debit_card && current.has_internet_banking);
+
+        let salary = Banking::bank_accounts(account(2)).unwrap();
+        assert_eq!(salary.account_type, b"SALARY".to_vec());
+        assert_eq!((salary.minimum_balance, salary.overdraft_limit), (0, None));
+
+        // A current account draws on its overdraft once it reaches the minimum balance
+        assert_ok!(Banking::withdraw(RuntimeOrigin::signed(account(1)), 1_200, None));
+        let current = Banking::bank_accounts(account(1)).unwrap();
+        assert_eq!((current.current_balance, current.overdraft_used), (500, 700));
+
+        // A salary account can be emptied, but not overdrawn
+        assert_ok!(Banking::withdraw(RuntimeOrigin::signed(account(2)), 1_000, None));
+        assert_noop!(
+            Banking::withdraw(RuntimeOrigin::signed(account(2)), 1, None),
+            Error::<Test>::InsufficientBalance
+        );
+    });
+}
+
+#[test]
+fn sub_account_funding_keeps_the_parent_above_its_minimum() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        open(2, 1_000);
+        let propose = |amount| {
+            Banking::add_sub_account(RuntimeOrigin::signed(account(1)), account(1), account(2), Some(amount), None)
+        };
+
+        assert_ok!(propose(950));
+        assert_noop!(
+            Banking::accept_sub_account(RuntimeOrigin::signed(account(2)), account(1)),
+            Error::<Test>::InsufficientBalance
+        );
+
+        // A fresh proposal replaces the one that could not be funded
+        assert_ok!(propose(900));
+        assert_ok!(Banking::accept_sub_account(RuntimeOrigin::signed(account(2)), account(1)));
+        assert_eq!((balance(1), balance(2)), (100, 1_900));
+    });
+}
+
+#[test]
+fn earmarked_funds_cannot_be_spent_until_released() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        let signed = || RuntimeOrigin::signed(account(1));
+        assert_ok!(Banking::allocate_to_ledger(signed(), label(b"rent"), 600, None));
+        assert_noop!(Banking::withdraw(signed(), 400, None), Error::<Test>::InsufficientBalance);
+        assert_noop!(
+            Banking::allocate_to_ledger(signed(), label(b"rent"), 500, None),
+            Error::<Test>::LedgerOverAllocated
+        );
+        assert_ok!(Banking::allocate_to_ledger(signed(), label(b"fees"), 100, None));
+        assert_noop!(
+            Banking::allocate_to_ledger(signed(), label(b"tax"), 100, None),
+            Error::<Test>::TooManyLedgers
+        );
+
+        assert_noop!(Banking::withdraw_from_ledger(signed(), label(b"tax"), 1), Error::<Test>::LedgerNotFound);
+        assert_noop!(
+            Banking::withdraw_from_ledger(signed(), label(b"rent"), 700),
+            Error::<Test>::LedgerInsufficientFunds
+        );
+        assert_ok!(Banking::withdraw_from_ledger(signed(), label(b"rent"), 600));
+        let ledgers = Banking::bank_accounts(account(1)).unwrap().sub_ledgers.into_inner();
+        assert_eq!(ledgers, vec![(label(b"fees"), 100)]);
+
+        assert_ok!(Banking::withdraw(signed(), 400, None));
+        assert_eq!(balance(1), 600);
+    });
+}
+
+#[test]
+fn reconcile_compares_the_ledger_with_the_funds_held() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        open(2, 2_000);
+        assert_noop!(Banking::reconcile(RuntimeOrigin::signed(account(1))), BadOrigin);
+
+        assert_ok!(Banking::reconcile(RuntimeOrigin::root()));
+        assert_eq!(banking_events().last(), Some(&Event::ReconciliationResult(3_000, 3_000, true)));
+
+        // Overdraft paid out leaves the pallet's funds and the running totals together
+        BankAccounts::<Test>::mutate(account(1), |acc| acc.as_mut().unwrap().overdraft_limit = Some(500));
+        assert_ok!(Banking::withdraw(RuntimeOrigin::signed(account(1)), 1_200, None));
+        assert_eq!(Banking::total_overdraft(), 300);
+        assert_ok!(Banking::reconcile(RuntimeOrigin::root()));
+        assert_eq!(banking_events().last(), Some(&Event::ReconciliationResult(1_800, 1_800, true)));
+
+        // A ledger balance the pallet holds no funds for is drift
+        TotalDeposits::<Test>::mutate(|total| *total += 1);
+        assert_noop!(Banking::reconcile(RuntimeOrigin::root()), Error::<Test>::LedgerDrift);
+    });
+}
+
+#[test]
+fn amounts_off_the_minimum_denomination_are_rejected_or_rounded() {
+    ExtBuilder::default()
+        .with(|| MinDenomination::set(100))
+        .build()
+        .execute_with(|| {
+            open(1, 1_000);
+            open(2, 1_000);
+            assert_noop!(
+                Banking::deposit(RuntimeOrigin::signed(account(1)), 150),
+                Error::<Test>::InvalidDenomination
+            );
+            assert_noop!(transfer(1, 2, 50), Error::<Test>::InvalidDenomination);
+            assert_ok!(Banking::deposit(RuntimeOrigin::signed(account(1)), 200));
+
+            // Rounding moves the whole units and leaves the remainder where it was
+            Denomination::set(DenominationPolicy::RoundDown);
+            assert_ok!(transfer(1, 2, 250));
+            assert_eq!((balance(1), balance(2)), (1_000, 1_200));
+            assert_eq!(banking_events().last(), Some(&Event::Transferred(account(1), account(2), 200)));
+            assert_noop!(transfer(1, 2, 99), Error::<Test>::InvalidDenomination);
+        });
+}
+
+#[test]
+fn currency_shortfalls_surface_as_insufficient_balance() {
+    ExtBuilder::default()
+        .with(|| MaxSingleTxn::set(u64::MAX))
+        .build()
+        .execute_with(|| {
+            open(1, 1_000);
+            // The ledger could take the credit, but the holder's own funds cannot cover it
+            assert_noop!(
+                Banking::deposit(RuntimeOrigin::signed(account(1)), ENDOWMENT),
+                Error::<Test>::InsufficientBalance
+            );
+
+            // The ledger allows the overdraft, but the pallet cannot pay it out
+            BankAccounts::<Test>::mutate(account(1), |acc| acc.as_mut().unwrap().overdraft_limit = Some(5_000));
+            assert_noop!(
+                Banking::withdraw(RuntimeOrigin::signed(account(1)), 3_000, None),
+                Error::<Test>::InsufficientBalance
+            );
+        });
+}
+
+#[test]
+fn whitelisted_accounts_only_send_to_listed_destinations() {
+    ExtBuilder::default().build().execute_with(|| {
+        for seed in 1..=4 {
+            open(seed, 1_000);
+        }
+        let signed = || RuntimeOrigin::signed(account(1));
+        assert_ok!(Banking::add_whitelist_entry(signed(), account(2)));
+        assert_noop!(Banking::add_whitelist_entry(signed(), account(2)), Error::<Test>::AlreadyWhitelisted);
+        // Entries have no effect until the whitelist is switched on
+        assert_ok!(transfer(1, 3, 100));
+
+        assert_ok!(Banking::set_whitelist_enabled(signed(), true));
+        assert_noop!(transfer(1, 3, 100), Error::<Test>::DestinationNotWhitelisted);
+        assert_ok!(transfer(1, 2, 100));
+
+        assert_ok!(Banking::add_whitelist_entry(signed(), account(3)));
+        assert_noop!(Banking::add_whitelist_entry(signed(), account(4)), Error::<Test>::TooManyWhitelistEntries);
+        assert_ok!(transfer(1, 3, 100));
+
+        assert_ok!(Banking::remove_whitelist_entry(signed(), account(3)));
+        assert_noop!(Banking::remove_whitelist_entry(signed(), account(3)), Error::<Test>::WhitelistEntryNotFound);
+        assert_noop!(transfer(1, 3, 100), Error::<Test>::DestinationNotWhitelisted);
+    });
+}
+
+#[test]
+fn single_transactions_above_the_cap_need_an_override() {
+    ExtBuilder::default()
+        .with(|| MaxSingleTxn::set(500))
+        .build()
+        .execute_with(|| {
+            open(1, 2_000);
+            open(2, 1_000);
+            let signed = || RuntimeOrigin::signed(account(1));
+            assert_noop!(Banking::deposit(signed(), 501), Error::<Test>::TxnExceedsLimit);
+            assert_noop!(Banking::withdraw(signed(), 501, None), Error::<Test>::TxnExceedsLimit);
+            assert_noop!(transfer(1, 2, 501), Error::<Test>::TxnExceedsLimit);
+            assert_ok!(transfer(1, 2, 500));
+
+            assert_noop!(Banking::set_high_limit_override(signed(), account(1), true), BadOrigin);
+            assert_ok!(Banking::set_high_limit_override(RuntimeOrigin::root(), account(1), true));
+            assert_ok!(transfer(1, 2, 501));
+            assert_eq!(balance(1), 999);
+        });
+}
+
+#[test]
+fn a_nominee_takes_over_an_inactive_account_after_the_delay() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        assert_ok!(Banking::set_nominee(RuntimeOrigin::signed(account(1)), Some(account(5))));
+        let claim = |seed| Banking::claim_succession(RuntimeOrigin::signed(account(seed)), account(1));
+
+        assert_noop!(claim(6), Error::<Test>::NotNominee);
+        assert_noop!(claim(5), Error::<Test>::AccountStillActive);
+
+        run_to_block(101);
+        assert_ok!(claim(5));
+        assert_eq!(banking_events().last(), Some(&Event::SuccessionInitiated(account(1), account(5), 111)));
+        assert_noop!(claim(5), Error::<Test>::SuccessionNotReady);
+
+        run_to_block(111);
+        assert_ok!(claim(5));
+        assert_eq!(Banking::bank_accounts(account(1)), None);
+        let inherited = Banking::bank_accounts(account(5)).expect("account moved to the nominee");
+        assert_eq!(inherited.account_holder, account(5));
+        assert_eq!((inherited.current_balance, inherited.nominee), (1_000, None));
+    });
+}
+
+#[test]
+fn activity_during_the_delay_stops_a_succession() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        assert_ok!(Banking::set_nominee(RuntimeOrigin::signed(account(1)), Some(account(5))));
+        run_to_block(101);
+        assert_ok!(Banking::claim_succession(RuntimeOrigin::signed(account(5)), account(1)));
+
+        assert_ok!(Banking::heartbeat(RuntimeOrigin::signed(account(1))));
+        run_to_block(111);
+        assert_noop!(
+            Banking::claim_succession(RuntimeOrigin::signed(account(5)), account(1)),
+            Error::<Test>::AccountStillActive
+        );
+    });
+}
+
+#[test]
+fn export_masks_identity_numbers_and_keeps_the_latest_transactions() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        open(2, 1_000);
+        assert_ok!(transfer(1, 2, 100));
+        assert_ok!(Banking::deposit(RuntimeOrigin::signed(account(1)), 50));
+        assert_ok!(Banking::withdraw(RuntimeOrigin::signed(account(1)), 30, None));
+        assert_ok!(transfer(2, 1, 20));
+
+        let export = Banking::export_account(&account(1)).expect("account exists");
+        assert_eq!(export.holder_pan, Some(b"XXXXXX234F".to_vec()));
+        assert_eq!(export.current_balance, 940);
+        let kinds: Vec<TxnKind> = export.recent_transactions.iter().map(|txn| txn.kind.clone()).collect();
+        assert_eq!(kinds, vec![TxnKind::Deposit, TxnKind::Withdrawal, TxnKind::TransferIn]);
+        assert_eq!(export.recent_transactions[2].counterparty, Some(account(2)));
+        assert!(encoding_is_stable(&export));
+
+        assert_eq!(Banking::export_account(&account(3)), None);
+    });
+}
+
+#[test]
+fn transfers_between_a_pair_are_capped_per_window() {
+    ExtBuilder::default()
+        .with(|| {
+            PairTransferWindow::set(10);
+            MaxPairTransferPerWindow::set(500);
+        })
+        .build()
+        .execute_with(|| {
+            for seed in 1..=3 {
+                open(seed, 2_000);
+            }
+            assert_ok!(transfer(1, 2, 300));
+            assert_noop!(transfer(1, 2, 300), Error::<Test>::PairLimitExceeded);
+            // Each direction and each pair has its own allowance
+            assert_ok!(transfer(2, 1, 300));
+            assert_ok!(transfer(1, 3, 300));
+
+            run_to_block(11);
+            assert_ok!(transfer(1, 2, 300));
+
+            // The sweep at block 20 drops only the records whose window has lapsed
+            run_to_block(21);
+            assert_eq!(Banking::pair_transfers(account(1), account(2)), Some((11, 300)));
+            assert_eq!(Banking::pair_transfers(account(1), account(3)), None);
+            assert_eq!(Banking::pair_transfers(account(2), account(1)), None);
+        });
+}
+
+#[test]
+fn inbound_only_accounts_receive_but_never_send() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        open(2, 1_000);
+        assert_noop!(Banking::set_inbound_only(RuntimeOrigin::signed(account(1)), account(1)), BadOrigin);
+        assert_ok!(Banking::set_inbound_only(RuntimeOrigin::root(), account(1)));
+
+        assert_ok!(transfer(2, 1, 100));
+        assert_ok!(Banking::deposit(RuntimeOrigin::signed(account(1)), 100));
+        assert_noop!(transfer(1, 2, 100), Error::<Test>::OutboundBlocked);
+        assert_noop!(Banking::withdraw(RuntimeOrigin::signed(account(1)), 100, None), Error::<Test>::OutboundBlocked);
+        assert_eq!(balance(1), 1_200);
+
+        assert_ok!(Banking::clear_inbound_only(RuntimeOrigin::root(), account(1)));
+        assert_ok!(transfer(1, 2, 100));
+    });
+}
+
+#[test]
+fn closure_waits_for_children_to_be_detached() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        open(2, 1_000);
+        link(1, 2);
+        let signed = || RuntimeOrigin::signed(account(1));
+        assert_noop!(Banking::finalize_closure(signed(), None), Error::<Test>::ClosureNotRequested);
+        assert_ok!(Banking::request_closure(signed()));
+        assert_eq!(banking_events().last(), Some(&Event::ClosureRequested(account(1), 11)));
+        assert_noop!(Banking::request_closure(signed()), Error::<Test>::ClosureAlreadyRequested);
+        assert_noop!(Banking::finalize_closure(signed(), None), Error::<Test>::ChildrenStillAttached);
+
+        assert_ok!(Banking::detach_sub_account(signed(), account(2)));
+        assert_ok!(Banking::finalize_closure(signed(), None));
+        assert_eq!(Banking::bank_accounts(account(1)).unwrap().status, Status::Closed);
+        assert_eq!(Banking::pending_closure(account(1)), None);
+        assert!(banking_events().contains(&Event::ClosureFinalized(account(1), 1_000)));
+    });
+}
+
+#[test]
+fn closure_is_cancelled_if_children_outlast_the_grace_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        open(2, 1_000);
+        link(1, 2);
+        assert_ok!(Banking::request_closure(RuntimeOrigin::signed(account(1))));
+
+        run_to_block(12);
+        assert_ok!(Banking::finalize_closure(RuntimeOrigin::signed(account(1)), None));
+        assert_eq!(banking_events().last(), Some(&Event::ClosureCancelled(account(1))));
+        assert_eq!(Banking::pending_closure(account(1)), None);
+        assert_eq!(Banking::bank_accounts(account(1)).unwrap().status, Status::Operative);
+        assert_eq!(balance(1), 1_000);
+    });
+}
+
+#[test]
+fn welcome_credit_is_paid_from_the_treasury_while_it_can_afford_it() {
+    ExtBuilder::default()
+        .with(|| WelcomeCredit::set(50))
+        .build()
+        .execute_with(|| {
+            open(1, 1_000);
+            assert_eq!(balance(1), 1_050);
+            assert!(Banking::welcome_credited(account(1)));
+            assert_eq!(Balances::free_balance(account(TREASURY)), ENDOWMENT - 50);
+            assert_eq!(banking_events().last(), Some(&Event::WelcomeCreditGranted(account(1), 50)));
+
+            // An underfunded treasury skips the gift without blocking the account
+            WelcomeCredit::set(ENDOWMENT);
+            open(2, 1_000);
+            assert_eq!(balance(2), 1_000);
+            assert!(!Banking::welcome_credited(account(2)));
+        });
+}
+
+#[test]
+fn projected_interest_matches_what_accrual_later_pays() {
+    ExtBuilder::default()
+        .with(|| InterestRate::set(Permill::from_percent(12)))
+        .build()
+        .execute_with(|| {
+            open(1, 100_000);
+            open(2, 100_000);
+            assert_ok!(Banking::set_capitalization(RuntimeOrigin::signed(account(2)), CapFrequency::Monthly));
+
+            // 10 a block; the monthly account also earns on the interest capitalized at block 101
+            assert_eq!(Banking::projected_interest(&account(1), 200), 2_000);
+            assert_eq!(Banking::projected_interest(&account(2), 200), 2_010);
+            assert_eq!(Banking::projected_interest(&account(3), 200), 0);
+
+            run_to_block(201);
+            for seed in [1, 2] {
+                assert_ok!(Banking::accrue_interest(RuntimeOrigin::signed(account(seed)), account(seed)));
+            }
+            let annual = Banking::bank_accounts(account(1)).unwrap();
+            assert_eq!(annual.current_balance + annual.accrued_interest, 102_000);
+            assert_eq!(balance(2), 102_010);
+        });
+}
+
+#[test]
+fn notifications_fire_only_for_the_kinds_opted_into() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        let flags = NotificationKind::OnWithdraw.flag() | NotificationKind::OnLowBalance.flag();
+        assert_ok!(Banking::set_notification_preferences(RuntimeOrigin::signed(account(1)), flags, 500));
+        let notified = || {
+            banking_events()
+                .into_iter()
+                .filter_map(|event| match event {
+                    Event::AccountNotification(_, kind) => Some(kind),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_ok!(Banking::deposit(RuntimeOrigin::signed(account(1)), 100));
+        assert!(notified().is_empty());
+        assert_ok!(Banking::withdraw(RuntimeOrigin::signed(account(1)), 100, None));
+        assert_eq!(notified(), vec![NotificationKind::OnWithdraw]);
+        assert_ok!(Banking::withdraw(RuntimeOrigin::signed(account(1)), 550, None));
+        assert_eq!(
+            notified(),
+            vec![NotificationKind::OnWithdraw, NotificationKind::OnWithdraw, NotificationKind::OnLowBalance]
+        );
+    });
+}
+
+#[test]
+fn full_creation_checks_identifiers_against_the_registry() {
+    ExtBuilder::default().build().execute_with(|| {
+        // MICR bank code `003` and IFSC prefix `HDFC` do not belong to the registered bank
+        assert_noop!(
+            open_full(1, b"SBIN0001234", b"400003001", REGISTERED_BANK),
+            Error::<Test>::InvalidBankIdentifiers
+        );
+        assert_noop!(
+            open_full(1, b"HDFC0001234", b"400002001", REGISTERED_BANK),
+            Error::<Test>::InvalidBankIdentifiers
+        );
+        // Malformed codes are refused whatever the bank
+        assert_noop!(open_full(1, b"SBIN1001234", b"400002001", b"OTHER BANK"), Error::<Test>::InvalidBankIdentifiers);
+        assert_noop!(open_full(1, b"SBIN0001234", b"40000200", b"OTHER BANK"), Error::<Test>::InvalidBankIdentifiers);
+
+        assert_ok!(open_full(1, b"SBIN0001234", b"400002001", REGISTERED_BANK));
+        assert_eq!(Banking::bank_accounts(account(1)).unwrap().micr_code, Some(b"400002001".to_vec()));
+        // Banks the registry does not know are only checked for format
+        assert_ok!(open_full(2, b"HDFC0001234", b"400240001", b"OTHER BANK"));
+    });
+}
+
+#[test]
+fn hierarchy_cycles_are_refused_and_corrupt_ones_still_terminate() {
+    ExtBuilder::default().build().execute_with(|| {
+        for seed in 1..=3 {
+            open(seed, 1_000);
+        }
+        link(1, 2);
+        link(2, 3);
+        assert_noop!(
+            Banking::add_sub_account(RuntimeOrigin::signed(account(3)), account(3), account(1), None, None),
+            Error::<Test>::HierarchyCycle
+        );
+
+        // Close the loop behind the pallet's back: 1 -> 2 -> 3 -> 1
+        BankAccounts::<Test>::mutate(account(3), |acc| acc.as_mut().unwrap().child_accounts.push(account(1)));
+        BankAccounts::<Test>::mutate(account(1), |acc| acc.as_mut().unwrap().parent_account = Some(account(3)));
+        assert_eq!(Banking::net_position(&account(1)), (3_000, 3));
+        assert_ok!(Banking::consolidate(RuntimeOrigin::signed(account(1)), account(1)));
+        assert_eq!((balance(1), balance(2), balance(3)), (2_800, 100, 100));
+
+        // Traversal also stops at the configured depth
+        MaxHierarchyDepth::set(1);
+        assert_eq!(Banking::net_position(&account(1)), (2_900, 2));
+    });
+}
+
+#[test]
+fn only_frozen_accounts_can_be_seized() {
+    ExtBuilder::default()
+        .with(|| {
+            VelocityThreshold::set(0);
+            FreezeOnVelocityAnomaly::set(true);
+        })
+        .build()
+        .execute_with(|| {
+            open(1, 1_000);
+            open(2, 1_000);
+            let seize = |origin| Banking::seize_account(origin, account(1), account(TREASURY));
+            assert_noop!(seize(RuntimeOrigin::root()), Error::<Test>::AccountNotFrozen);
+
+            // Any outflow trips the anomaly and freezes the sender
+            assert_ok!(transfer(1, 2, 100));
+            assert_noop!(seize(RuntimeOrigin::signed(account(2))), BadOrigin);
+            assert_ok!(seize(RuntimeOrigin::root()));
+
+            let seized = Banking::bank_accounts(account(1)).unwrap();
+            assert_eq!((seized.status, seized.current_balance), (Status::Closed, 0));
+            assert_eq!(Balances::free_balance(account(TREASURY)), ENDOWMENT + 900);
+            assert_eq!(Banking::seizure(account(1)), Some((account(TREASURY), 900, 1)));
+            assert_eq!(banking_events().last(), Some(&Event::AccountSeized(account(1), account(TREASURY), 900)));
+        });
+}
+
+#[test]
+fn spending_limits_reset_when_the_period_rolls_over() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 10_000);
+        open(2, 1_000);
+        assert_ok!(Banking::set_spending_limit(RuntimeOrigin::signed(account(1)), LimitPeriod::Daily, 500));
+        assert_ok!(transfer(1, 2, 300));
+        assert_noop!(
+            Banking::withdraw(RuntimeOrigin::signed(account(1)), 300, None),
+            Error::<Test>::SpendingLimitExceeded
+        );
+
+        // Days start on multiples of `BlocksPerDay`
+        run_to_block(9);
+        assert_noop!(transfer(1, 2, 300), Error::<Test>::SpendingLimitExceeded);
+        run_to_block(10);
+        assert_ok!(Banking::withdraw(RuntimeOrigin::signed(account(1)), 300, None));
+        assert_ok!(transfer(1, 2, 200));
+        assert_noop!(transfer(1, 2, 1), Error::<Test>::SpendingLimitExceeded);
+    });
+}
+
+#[test]
+fn calls_on_unknown_accounts_fail_without_side_effects() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        assert_noop!(Banking::deposit(RuntimeOrigin::signed(account(2)), 100), Error::<Test>::AccountNotFound);
+        assert_noop!(Banking::heartbeat(RuntimeOrigin::signed(account(2))), Error::<Test>::AccountNotFound);
+        assert_noop!(transfer(1, 2, 100), Error::<Test>::AccountNotFound);
+        assert_noop!(transfer(2, 1, 100), Error::<Test>::AccountNotFound);
+        assert_eq!(Banking::bank_accounts(account(2)), None);
+    });
+}
+
+#[test]
+fn credits_that_would_overflow_a_balance_are_refused() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        open(2, 1_000);
+        BankAccounts::<Test>::mutate(account(2), |acc| acc.as_mut().unwrap().current_balance = u64::MAX - 50);
+
+        assert_noop!(transfer(1, 2, 100), Error::<Test>::BalanceOverflow);
+        assert_noop!(Banking::deposit(RuntimeOrigin::signed(account(2)), 100), Error::<Test>::BalanceOverflow);
+
+        let signed = || RuntimeOrigin::signed(account(2));
+        assert_ok!(Banking::allocate_to_ledger(signed(), label(b"rent"), u64::MAX - 50, None));
+        assert_noop!(
+            Banking::allocate_to_ledger(signed(), label(b"fees"), 100, None),
+            Error::<Test>::BalanceOverflow
+        );
+    });
+}
+
+#[test]
+fn metadata_keys_are_unique_and_entries_bounded() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        let set = |seed: u8, key: &[u8], value: &[u8]| {
+            Banking::set_account_metadata(
+                RuntimeOrigin::signed(account(seed)),
+                key.to_vec().try_into().unwrap(),
+                value.to_vec().try_into().unwrap(),
+            )
+        };
+        let remove = |key: &[u8]| {
+            Banking::remove_account_metadata_key(RuntimeOrigin::signed(account(1)), key.to_vec().try_into().unwrap())
+        };
+
+        assert_ok!(set(1, b"theme", b"dark"));
+        assert_ok!(set(1, b"theme", b"light"));
+        assert_ok!(set(1, b"lang", b"en"));
+        assert_noop!(set(1, b"tz", b"utc"), Error::<Test>::TooManyMetadataEntries);
+        assert_eq!(
+            Banking::metadata_of(account(1)),
+            vec![(b"theme".to_vec(), b"light".to_vec()), (b"lang".to_vec(), b"en".to_vec())]
+        );
+
+        assert_noop!(remove(b"tz"), Error::<Test>::MetadataKeyNotFound);
+        assert_ok!(remove(b"theme"));
+        assert_ok!(set(1, b"tz", b"utc"));
+        assert_eq!(
+            Banking::metadata_of(account(1)),
+            vec![(b"lang".to_vec(), b"en".to_vec()), (b"tz".to_vec(), b"utc".to_vec())]
+        );
+
+        assert_noop!(set(2, b"theme", b"dark"), Error::<Test>::AccountNotFound);
+    });
+}
+
+#[test]
+fn expired_earmarks_are_swept_back_into_the_spendable_balance() {
+    ExtBuilder::default()
+        .with(|| LedgerExpirySweepInterval::set(5))
+        .build()
+        .execute_with(|| {
+            open(1, 1_000);
+            let signed = || RuntimeOrigin::signed(account(1));
+            assert_ok!(Banking::allocate_to_ledger(signed(), label(b"rent"), 500, Some(8)));
+            assert_ok!(Banking::allocate_to_ledger(signed(), label(b"fees"), 200, None));
+            assert_noop!(Banking::withdraw(signed(), 300, None), Error::<Test>::InsufficientBalance);
+
+            // The sweep at block 5 finds nothing due yet
+            run_to_block(6);
+            assert_eq!(Banking::ledger_expiries(account(1)).len(), 1);
+
+            run_to_block(11);
+            assert!(banking_events().contains(&Event::HoldExpired(account(1), label(b"rent"), 500)));
+            let ledgers = Banking::bank_accounts(account(1)).unwrap().sub_ledgers.into_inner();
+            assert_eq!(ledgers, vec![(label(b"fees"), 200)]);
+            assert!(Banking::ledger_expiries(account(1)).is_empty());
+            assert_ok!(Banking::withdraw(signed(), 300, None));
+        });
+}
+
+#[test]
+fn a_failure_part_way_through_a_multi_account_update_leaves_every_account_untouched() {
+    ExtBuilder::default().build().execute_with(|| {
+        for seed in 1..=3 {
+            open(seed, 1_000);
+        }
+        link(1, 2);
+        link(1, 3);
+
+        // Both children are debited before the root's credit overflows
+        BankAccounts::<Test>::mutate(account(1), |acc| acc.as_mut().unwrap().current_balance = u64::MAX - 1_000);
+        assert_noop!(
+            Banking::consolidate(RuntimeOrigin::signed(account(1)), account(1)),
+            Error::<Test>::BalanceOverflow
+        );
+        assert_eq!((balance(2), balance(3)), (1_000, 1_000));
+        assert!(Banking::recent_txns(account(2)).is_empty());
+
+        // A destination that refuses the credit rolls back the sender's debit and limit records
+        BankAccounts::<Test>::mutate(account(3), |acc| acc.as_mut().unwrap().status = Status::Dormant);
+        assert_noop!(transfer(2, 3, 100), Error::<Test>::AccountNotOperative);
+        assert_eq!(Banking::pair_transfers(account(2), account(3)), None);
+    });
+}
+
+#[test]
+fn overdraft_alert_fires_once_when_utilization_crosses_the_threshold() {
+    ExtBuilder::default().build().execute_with(|| {
+        open_from_template(1, AccountTemplate::Current, 1_000);
+        // Liquidity from another holder, so overdraft payouts can leave the pallet
+        open(2, 5_000);
+        let threshold = Some(Permill::from_percent(50));
+        assert_noop!(Banking::set_overdraft_alert(RuntimeOrigin::signed(account(1)), account(1), threshold), BadOrigin);
+        assert_ok!(Banking::set_overdraft_alert(RuntimeOrigin::root(), account(1), threshold));
+        let alerts = || {
+            banking_events()
+                .into_iter()
+                .filter(|event| matches!(event, Event::OverdraftUtilizationHigh(..)))
+                .collect::<Vec<_>>()
+        };
+
+        // 30% of the overdraft drawn, then 60%, then 70%: only the crossing raises the alert
+        assert_ok!(Banking::withdraw(RuntimeOrigin::signed(account(1)), 800, None));
+        assert!(alerts().is_empty());
+        assert_ok!(Banking::withdraw(RuntimeOrigin::signed(account(1)), 300, None));
+        assert_ok!(Banking::withdraw(RuntimeOrigin::signed(account(1)), 100, None));
+        assert_eq!(alerts(), vec![Event::OverdraftUtilizationHigh(account(1), Permill::from_percent(60))]);
+    });
+}
+
+#[test]
+fn closing_an_account_archives_a_hash_of_its_final_state() {
+    ExtBuilder::default().build().execute_with(|| {
+        open(1, 1_000);
+        assert_ok!(Banking::request_closure(RuntimeOrigin::signed(account(1))));
+        assert_ok!(Banking::finalize_closure(RuntimeOrigin::signed(account(1)), None));
+
+        let (closed_at, hash, final_state) = Banking::closed_account(account(1)).expect("closure archived");
+        assert_eq!(closed_at, 1);
+        assert_eq!(final_state, Banking::bank_accounts(account(1)).unwrap());
+        assert_eq!(hash, sp_io::hashing::blake2_256(&final_state.encode()));
+        assert!(banking_events().contains(&Event::AccountClosureCertificate(account(1), 1_000, closed_at, hash)));
+
+        // Later changes to the live record do not disturb the proof
+        BankAccounts::<Test>::mutate(account(1), |acc| acc.as_mut().unwrap().nominee = Some(account(5)));
+        let live = Banking::bank_accounts(account(1)).unwrap();
+        assert_ne!(hash, sp_io::hashing::blake2_256(&live.encode()));
+        assert_eq!(Banking::closed_account(account(1)).map(|(_, hash, _)| hash), Some(hash));
+    });
+}
+
+#[test]
+fn frozen_accounts_accrue_interest_only_when_configured() {
+    ExtBuilder::default()
+        .with(|| InterestRate::set(Permill::from_percent(12)))
+        .build()
+        .execute_with(|| {
+            open(1, 100_000);
+            open(2, 100_000);
+            for seed in [1, 2] {
+                BankAccounts::<Test>::mutate(account(seed), |acc| acc.as_mut().unwrap().status = Status::Frozen);
+            }
+            assert_noop!(
+                Banking::set_accrue_while_frozen(RuntimeOrigin::signed(account(2)), account(2), true),
+                BadOrigin
+            );
+            assert_ok!(Banking::set_accrue_while_frozen(RuntimeOrigin::root(), account(2), true));
+            assert_eq!(Banking::projected_interest(&account(1), 100), 0);
+            assert_eq!(Banking::projected_interest(&account(2), 100), 1_000);
+
+            run_to_block(101);
+            for seed in [1, 2] {
+                assert_ok!(Banking::accrue_interest(RuntimeOrigin::signed(account(seed)), account(seed)));
+            }
+            assert_eq!(Banking::bank_accounts(account(1)).unwrap().accrued_interest, 0);
+            assert_eq!(Banking::bank_accounts(account(2)).unwrap().accrued_interest, 1_000);
+
+            // Frozen time is forfeited, not paid out once the account thaws
+            BankAccounts::<Test>::mutate(account(1), |acc| acc.as_mut().unwrap().status = Status::Operative);
+            run_to_block(201);
+            assert_ok!(Banking::accrue_interest(RuntimeOrigin::signed(account(1)), account(1)));
+            assert_eq!(Banking::bank_accounts(account(1)).unwrap().accrued_interest, 1_000);
+        });
+}
+
+#[test]
+fn dormancy_is_preceded_by_a_single_warning() {
+    ExtBuilder::default()
+        .with(|| {
+            DormancyPeriod::set(50);
+            DormancySweepInterval::set(5);
+        })
+        .build()
+        .execute_with(|| {
+            open(1, 1_000);
+            let warnings = || {
+                banking_events().into_iter().filter(|event| matches!(event, Event::DormancyWarning(..))).count()
+            };
+
+            // Dormant from block 51; the sweep at 45 is the first within the warning period
+            run_to_block(41);
+            assert_eq!(warnings(), 0);
+            run_to_block(46);
+            assert_eq!(banking_events().last(), Some(&Event::DormancyWarning(account(1), 51)));
+            run_to_block(51);
+            assert_eq!(warnings(), 1);
+
+            run_to_block(56);
+            assert_eq!(Banking::bank_accounts(account(1)).unwrap().status, Status::Dormant);
+            assert_eq!(banking_events().last(), Some(&Event::AccountDormant(account(1))));
+            assert_ok!(Banking::heartbeat(RuntimeOrigin::signed(account(1))));
+            assert_eq!(Banking::bank_accounts(account(1)).unwrap().status, Status::Operative);
+            assert!(banking_events().contains(&Event::AccountReactivated(account(1))));
+        });
+}
+
+#[test]
+fn activity_after_a_warning_moves_the_dormancy_date() {
+    ExtBuilder::default()
+        .with(|| {
+            DormancyPeriod::set(50);
+            DormancySweepInterval::set(5);
+        })
+        .build()
+        .execute_with(|| {
+            open(1, 1_000);
+            run_to_block(46);
+            assert_ok!(Banking::heartbeat(RuntimeOrigin::signed(account(1))));
+
+            run_to_block(91);
+            assert_eq!(Banking::bank_accounts(account(1)).unwrap().status, Status::Operative);
+            assert_eq!(banking_events().last(), Some(&Event::DormancyWarning(account(1), 96)));
+        });
+}
@@ -3,19 +3,38 @@
 use core::f32::consts::E;
 
 use frame_support::{
-    decl_module, decl_storage, decl_event, decl_error, 
-    traits::{Get, Randomness},
-    weights::Weight,
-    codec::{Encode, Decode},
+    decl_module, decl_storage, decl_event, decl_error, ensure, Parameter,
+    traits::{Filter, Get, Randomness},
+    dispatch::{DispatchResult, DispatchResultWithPostInfo, PostDispatchInfo},
+    weights::{Pays, Weight},
+    codec::{Codec, Encode, Decode},
+    storage::{migration::storage_key_iter, with_transaction},
+    Twox64Concat,
 };
 
+use frame_support::traits::{Currency, EnsureOrigin, ExistenceRequirement, ReservableCurrency};
 use frame_system::ensure_signed;
-use sp_std::vec::Vec;
-use sp_runtime::traits::{Zero, Saturating};
+use sp_std::{marker::PhantomData, vec::Vec};
+use sp_runtime::{
+    ConsensusEngineId, DigestItem, DispatchError, Permill, SaturatedConversion, TransactionOutcome,
+    traits::{DispatchInfoOf, One, SignedExtension, Zero, Saturating},
+    transaction_validity::{
+        InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+    },
+};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
 
 pub trait Config: frame_system::Config {
     type Event: From<Event<Self>> + Into<<Self as frame_system::Config>::Event>;
     
+    /// Origin allowed to perform administrative trust actions
+    type AdminOrigin: EnsureOrigin<Self::Origin>;
+    
     /// Maximum trust score a node can have
     type MaxTrustScore: Get<f32>;
     
@@ -27,43 +46,551 @@ pub trait Config: frame_system::Config {
     
     /// Trust score penalty for failed validation
     type FailurePenalty: Get<f32>;
+
+    /// Blocks without a penalty after which successful validations earn the recovery bonus
+    type PenaltyCooldown: Get<Self::BlockNumber>;
+
+    /// Multiplier applied to the trust increase once the penalty cooldown has passed
+    type RecoveryMultiplier: Get<f32>;
+
+    /// Maximum trust updates a single reporter may submit per block
+    type MaxUpdatesPerReporterPerBlock: Get<u32>;
+    
+    /// Store an exponential moving average of the score instead of the raw event-driven value
+    type UseEmaSmoothing: Get<bool>;
+    
+    /// Weight given to the latest raw score when EMA smoothing is enabled
+    type EmaAlpha: Get<Permill>;
+    
+    /// Archive removed validators instead of deleting their trust data outright
+    type ArchiveRemoved: Get<bool>;
+    
+    /// Blocks an archived validator is kept before being pruned
+    type ArchiveRetention: Get<Self::BlockNumber>;
+    
+    /// Minimum validators that must remain after any removal
+    type MinActiveValidators: Get<u32>;
+    
+    /// Score margin above the removal threshold within which a validator is reported as at risk
+    type AtRiskMargin: Get<f32>;
+    
+    /// Scale failure penalties down for long-tenured validators
+    type TenurePenaltyScaling: Get<bool>;
+    
+    /// Blocks of tenure that earn one step of penalty leniency
+    type TenureLeniencyPeriod: Get<Self::BlockNumber>;
+    
+    /// Penalty reduction granted per tenure step
+    type TenureLeniencyStep: Get<f32>;
+    
+    /// Maximum total penalty reduction from tenure
+    type MaxTenureLeniency: Get<f32>;
+    
+    /// Maximum number of validators in the trust system
+    type MaxValidators: Get<u32>;
+    
+    /// What `initialize_validator` does once `MaxValidators` is reached
+    type OnFull: Get<FullSetPolicy>;
+    
+    /// Trust score given to newly added validators
+    type InitialTrustScore: Get<f32>;
+    
+    /// Blocks per trust season; zero disables seasonal resets
+    type SeasonLength: Get<Self::BlockNumber>;
+    
+    /// Fraction of each validator's distance from `InitialTrustScore` kept across a season reset
+    type CarryoverFactor: Get<Permill>;
+    
+    /// Consecutive successes between participation milestones; zero disables milestones
+    type MilestoneInterval: Get<u32>;
+    
+    /// One-time trust increase granted at each milestone
+    type MilestoneBonus: Get<f32>;
+    
+    /// Source of validator stake for stake-weighted voting
+    type StakeLookup: StakeLookup<Self::AccountId>;
+    
+    /// Whether vote weight is pure trust or trust multiplied by stake
+    type VoteWeighting: Get<VoteWeighting>;
+    
+    /// Source of prior reputation for validators onboarded from elsewhere
+    type ReputationBootstrap: ReputationBootstrap<Self::AccountId>;
+    
+    /// Escalate failure penalties for validators scoring below the network average
+    type BelowAverageEscalation: Get<bool>;
+    
+    /// Penalty multiplier applied to below-average validators when escalation is enabled
+    type BelowAverageMultiplier: Get<f32>;
+    
+    /// Blocks of inactivity over which a validator's trust halves; zero disables decay
+    type HalfLife: Get<Self::BlockNumber>;
+    
+    /// Trust score below which a penalized validator is moved onto probation
+    type ProbationThreshold: Get<f32>;
+    
+    /// Consecutive successes a probationary validator needs to return to `Trusted`
+    type PromotionThreshold: Get<u32>;
+    
+    /// Steps a full trust score of 1.0 maps to in events (e.g. 100 for percent, 10_000 for
+    /// basis points); stored scores keep their full precision
+    type EventScorePrecision: Get<u32>;
+    
+    /// Trust changes retained per validator for audit; the oldest are dropped first
+    type MaxHistoryLength: Get<u32>;
+    
+    /// Blocks without validations or heartbeats after which a validator is auto-exited; zero disables
+    type AutoExitPeriod: Get<Self::BlockNumber>;
+    
+    /// Currency used to post dispute bonds
+    type Currency: ReservableCurrency<Self::AccountId>;
+    
+    /// Amount reserved when filing a dispute; returned if upheld, slashed if rejected
+    type DisputeBond: Get<BalanceOf<Self>>;
+    
+    /// Recent validation outcomes kept per validator for the health score's success rate
+    type HealthWindow: Get<u32>;
+    
+    /// Blocks of silence over which the health score's recency component falls to zero
+    type HealthRecencyPeriod: Get<Self::BlockNumber>;
+    
+    /// Relative weight of the trust score in the health score
+    type HealthTrustWeight: Get<u32>;
+    
+    /// Relative weight of the recent success rate in the health score
+    type HealthSuccessWeight: Get<u32>;
+    
+    /// Relative weight of recency in the health score
+    type HealthRecencyWeight: Get<u32>;
+    
+    /// Include the signed basis-point change in `TrustScoreUpdated` for delta-based indexers
+    type EmitScoreDelta: Get<bool>;
+    
+    /// Trust score below which validators are left off the public leaderboard
+    type LeaderboardMinScore: Get<f32>;
+    
+    /// Leaderboard entries written into each block's digest; zero disables the digest
+    type DigestTopN: Get<u32>;
+    
+    /// Largest multiple of the base slash a low-trust validator can be charged
+    type MaxSlashMultiple: Get<u32>;
+    
+    /// Successful validations after which a validator counts as established; failures do not
+    /// count against it
+    type EstablishedValidations: Get<u32>;
+    
+    /// Score a single penalty cannot push an established validator below
+    type EstablishedFloor: Get<f32>;
+    
+    /// Blocks a new validator waits in the onboarding queue; zero activates immediately
+    type OnboardingDelay: Get<Self::BlockNumber>;
+    
+    /// Calls that only validators able to validate may submit, enforced by `CheckValidatorTrust`
+    type TrustGatedCalls: Filter<<Self as frame_system::Config>::Call>;
+    
+    /// Cross-chain transport for exported trust payloads
+    type XcmSender: XcmSender;
+    
+    /// Blocks that must pass between two cross-chain exports of the same validator
+    type ExportCooldown: Get<Self::BlockNumber>;
+    
+    /// Also suspend a validator from validating after each failed validation
+    type SuspendOnFailure: Get<bool>;
+    
+    /// Suspension per consecutive failure, in blocks
+    type SuspensionLength: Get<Self::BlockNumber>;
+    
+    /// Longest suspension a failure streak can earn, in blocks
+    type MaxSuspensionLength: Get<Self::BlockNumber>;
+    
+    /// Account that validator rewards are paid from
+    type RewardSource: Get<Self::AccountId>;
+    
+    /// Most validations one `update_trust_score_batch` call may report
+    type MaxBatchSize: Get<u32>;
+}
+
+type TrustDataOf<T> = NodeTrustData<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber>;
+
+type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Supplies a starting trust score for a validator with known prior reputation
+pub trait ReputationBootstrap<AccountId> {
+    fn starting_score(who: &AccountId) -> Option<f32>;
+}
+
+/// Scales slashes by validator trust, for slashing integrations that report offences
+pub trait SlashScaling<AccountId, Balance> {
+    fn slash_amount(who: &AccountId, base: Balance) -> Balance;
+}
+
+/// Delivers an encoded trust payload to another chain, e.g. over XCM or a bridge
+pub trait XcmSender {
+    /// Where a payload can be sent
+    type Destination: Parameter;
+    
+    fn send(dest: Self::Destination, payload: Vec<u8>) -> Result<(), ()>;
+}
+
+/// No cross-chain transport configured; every export fails
+impl XcmSender for () {
+    type Destination = ();
+    
+    fn send(_dest: (), _payload: Vec<u8>) -> Result<(), ()> {
+        Err(())
+    }
+}
+
+/// Default bootstrap with no prior reputation for anyone
+impl<AccountId> ReputationBootstrap<AccountId> for () {
+    fn starting_score(_who: &AccountId) -> Option<f32> {
+        None
+    }
+}
+
+/// Looks up the stake backing a validator
+pub trait StakeLookup<AccountId> {
+    fn stake_of(who: &AccountId) -> u128;
+}
+
+/// Default lookup that treats every validator as holding one unit of stake
+impl<AccountId> StakeLookup<AccountId> for () {
+    fn stake_of(_who: &AccountId) -> u128 {
+        1
+    }
+}
+
+/// How a validator's vote weight is derived
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VoteWeighting {
+    /// Trust score alone
+    PureTrust,
+    /// Trust score multiplied by stake
+    TrustTimesStake,
+}
+
+/// Behavior when a new validator is added to a full validator set
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FullSetPolicy {
+    /// Refuse the new validator
+    Reject,
+    /// Evict the lowest-scoring unprotected validator to make room
+    EvictLowest,
+}
+
+/// Standing of a validator within the trust system
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ValidatorTier {
+    /// In good standing
+    Trusted,
+    /// Recently penalized below `ProbationThreshold`; must rebuild a clean streak
+    Probation,
+}
+
+/// Integer vote weight applied per trust tier, with flagged validators weighed separately
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TierVoteWeights {
+    pub trusted: u32,
+    pub probation: u32,
+    pub flagged: u32,
+}
+
+impl TierVoteWeights {
+    /// Weight for a validator in `tier`, or the flagged weight if it is flagged for removal
+    pub fn weight_of(&self, tier: ValidatorTier, flagged: bool) -> u32 {
+        match (flagged, tier) {
+            (true, _) => self.flagged,
+            (false, ValidatorTier::Trusted) => self.trusted,
+            (false, ValidatorTier::Probation) => self.probation,
+        }
+    }
+    
+    /// Whether a better standing never carries less weight than a worse one
+    pub fn is_monotonic(&self) -> bool {
+        self.trusted >= self.probation && self.probation >= self.flagged
+    }
+}
+
+impl Default for TierVoteWeights {
+    fn default() -> Self {
+        TierVoteWeights { trusted: 3, probation: 1, flagged: 0 }
+    }
+}
+
+/// Why a validator was flagged for removal
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RemovalReason {
+    /// Trust score fell below `REMOVAL_THRESHOLD`
+    LowTrust,
+    /// Silent for `AutoExitPeriod`
+    Inactive,
+}
+
+/// Storage layout versions, used to gate migrations
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Releases {
+    /// Original layout; `last_updated` may be unset on legacy entries
+    V1,
+    /// Every entry carries a valid `last_updated`
+    V2,
+    /// Block fields are full block numbers and flagged entries carry a removal reason
+    V3,
+}
+
+impl Default for Releases {
+    fn default() -> Self {
+        Releases::V1
+    }
 }
 
+/// Trust score below which a validator is flagged for removal
+pub const REMOVAL_THRESHOLD: f32 = 0.1;
+
+/// Most failures simulated when estimating distance to removal; anything further is out of reach
+const MAX_RISK_ESTIMATE: u32 = 32;
+
+/// Engine id of the consensus digest item carrying the top validators' scores
+pub const TRUST_ENGINE_ID: ConsensusEngineId = *b"TRST";
+
+/// `InvalidTransaction::Custom` code for a gated call from a validator that cannot validate
+pub const LOW_TRUST_TRANSACTION: u8 = 1;
+
+/// Scratch key holding the pre-upgrade entry count between the try-runtime hooks
+#[cfg(feature = "try-runtime")]
+const PRE_UPGRADE_ENTRIES_KEY: &[u8] = b":trust-score:pre-upgrade-entries";
+
+/// Entries returned per page of the trust audit export
+pub const AUDIT_PAGE_SIZE: u32 = 500;
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
-pub struct NodeTrustData<AccountId> {
+pub struct NodeTrustData<AccountId, BlockNumber> {
     pub validator: AccountId,
     pub trust_score: f32,
+    /// Vote-driven score before smoothing; `trust_score` is its EMA when smoothing is enabled
+    pub raw_score: f32,
     pub successful_validations: u32,
     pub failed_validations: u32,
-    pub last_updated: u32,
+    pub success_streak: u32,
+    pub last_updated: BlockNumber,
+    pub joined_at: BlockNumber,
+    pub last_penalty_block: Option<BlockNumber>,
     pub flagged_for_removal: bool, 
+    pub tier: ValidatorTier,
+    pub removal_reason: Option<RemovalReason>,
+}
+
+/// `NodeTrustData` as laid out under `Releases::V1`, kept only to decode legacy entries
+#[derive(Encode, Decode)]
+struct OldNodeTrustData<AccountId> {
+    validator: AccountId,
+    trust_score: f32,
+    successful_validations: u32,
+    failed_validations: u32,
+    last_updated: u32,
+    flagged_for_removal: bool,
+}
+
+/// `NodeTrustData` as laid out under `Releases::V2`: `u32` block fields and no removal reason
+#[derive(Encode, Decode)]
+struct V2NodeTrustData<AccountId> {
+    validator: AccountId,
+    trust_score: f32,
+    successful_validations: u32,
+    failed_validations: u32,
+    success_streak: u32,
+    last_updated: u32,
+    joined_at: u32,
+    last_penalty_block: Option<u32>,
+    flagged_for_removal: bool,
+    tier: ValidatorTier,
+}
+
+impl<AccountId> V2NodeTrustData<AccountId> {
+    fn upgrade<BlockNumber: From<u32>>(self) -> NodeTrustData<AccountId, BlockNumber> {
+        NodeTrustData {
+            validator: self.validator,
+            trust_score: self.trust_score,
+            raw_score: self.trust_score,
+            successful_validations: self.successful_validations,
+            failed_validations: self.failed_validations,
+            success_streak: self.success_streak,
+            last_updated: self.last_updated.into(),
+            joined_at: self.joined_at.into(),
+            last_penalty_block: self.last_penalty_block.map(Into::into),
+            flagged_for_removal: self.flagged_for_removal,
+            tier: self.tier,
+            // Low trust was the only way to be flagged under V2
+            removal_reason: if self.flagged_for_removal { Some(RemovalReason::LowTrust) } else { None },
+        }
+    }
+}
+
+/// Cross-chain trust payload. New versions are added as variants so receivers can still
+/// decode what they understand.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub enum TrustExport<AccountId, BlockNumber> {
+    V1 {
+        validator: AccountId,
+        /// Trust score in basis points
+        trust_score: u32,
+        successful_validations: u32,
+        failed_validations: u32,
+        tier: ValidatorTier,
+        flagged_for_removal: bool,
+        last_updated: BlockNumber,
+    },
+}
+
+/// Everything a dashboard shows for one validator, gathered in a single read
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct ValidatorSummary<BlockNumber> {
+    /// Trust score in basis points
+    pub trust_score: u32,
+    pub tier: ValidatorTier,
+    /// 1-based position among all validators ranked by trust
+    pub rank: Option<u32>,
+    /// Share of the last `HealthWindow` validations that matched, in basis points
+    pub recent_success_rate: Option<u32>,
+    pub success_streak: u32,
+    pub failure_streak: u32,
+    pub successful_validations: u32,
+    pub failed_validations: u32,
+    pub flagged_for_removal: bool,
+    pub removal_reason: Option<RemovalReason>,
+    pub protected: bool,
+    pub suspended_until: Option<BlockNumber>,
+    pub last_updated: BlockNumber,
+    pub health_score: Option<u32>,
 }
 
 decl_storage! {
     trait Store for Module<T: Config> as TrustScore {
         /// Trust scores for validator nodes
         TrustScores get(fn trust_scores): 
-            map hasher(blake2_128_concat) T::AccountId => Option<NodeTrustData<T::AccountId>>;
+            map hasher(blake2_128_concat) T::AccountId => Option<TrustDataOf<T>>;
         
         /// List of all validators with trust scores
         ValidatorList get(fn validator_list): Vec<T::AccountId>;
         
+        /// Validators currently flagged for removal, so an empty cleanup is detected without a scan
+        FlaggedCount get(fn flagged_count): u32;
+        
         /// Global trust score statistics
         AverageTrustScore get(fn average_trust_score): f32 = 0.5;
         
         /// Minimum trust score required for validation
         MinValidationTrust get(fn min_validation_trust): f32 = 0.4;
+        
+        /// Trust updates submitted by each reporter in the current block
+        ReporterUpdates get(fn reporter_updates):
+            map hasher(blake2_128_concat) T::AccountId => u32;
+        
+        /// Final trust data of removed validators and the block they were removed at
+        RemovedValidators get(fn removed_validators):
+            map hasher(blake2_128_concat) T::AccountId => Option<(TrustDataOf<T>, T::BlockNumber)>;
+        
+        /// Archived validators due for pruning at a given block
+        ArchiveExpiry get(fn archive_expiry):
+            map hasher(twox_64_concat) T::BlockNumber => Vec<T::AccountId>;
+        
+        /// Validators ranked by trust score (basis points), refreshed during idle time
+        Leaderboard get(fn leaderboard): Vec<(T::AccountId, u32)>;
+        
+        /// Set when scores changed since the last average/leaderboard refresh
+        StatsDirty get(fn stats_dirty): bool;
+        
+        /// Validation rounds marked as network-wide faults, exempt from penalties
+        FaultedRounds get(fn faulted_rounds): map hasher(twox_64_concat) u32 => bool;
+        
+        /// Validators exempt from eviction and other automatic removal
+        ProtectedValidators get(fn protected_validators):
+            map hasher(blake2_128_concat) T::AccountId => bool;
+        
+        /// Index of the current trust season
+        CurrentSeason get(fn current_season): u32;
+        
+        /// Scores (basis points) as they stood at the end of each season
+        SeasonSnapshots get(fn season_snapshots):
+            map hasher(twox_64_concat) u32 => Vec<(T::AccountId, u32)>;
+        
+        /// Recent trust changes per validator as (block, score in basis points), oldest first
+        TrustHistory get(fn trust_history):
+            map hasher(blake2_128_concat) T::AccountId => Vec<(T::BlockNumber, u32)>;
+        
+        /// While set, trust updates are recorded as events but leave scores untouched
+        TrustAdjustmentsPaused get(fn trust_adjustments_paused): bool;
+        
+        /// Block adjustments were paused at, so the decay clock can be moved on by the pause on resume
+        PausedSince get(fn paused_since): Option<T::BlockNumber>;
+        
+        /// Block the next auto-exit scan is due at; a boundary passed while paused stays due
+        NextAutoExit get(fn next_auto_exit): Option<T::BlockNumber>;
+        
+        /// Block the next season reset is due at; a boundary passed while paused stays due
+        NextSeasonReset get(fn next_season_reset): Option<T::BlockNumber>;
+        
+        /// Block each validator last validated or sent a heartbeat
+        LastSeen get(fn last_seen): map hasher(blake2_128_concat) T::AccountId => Option<T::BlockNumber>;
+        
+        /// Open dispute per validator: block of the disputed penalty and the bond posted
+        Disputes get(fn disputes):
+            map hasher(blake2_128_concat) T::AccountId => Option<(T::BlockNumber, BalanceOf<T>)>;
+        
+        /// Outcomes of each validator's last `HealthWindow` validations, oldest first
+        RecentOutcomes get(fn recent_outcomes): map hasher(blake2_128_concat) T::AccountId => Vec<bool>;
+        
+        /// Validators waiting to be activated, with the block they become active
+        PendingValidators get(fn pending_validators):
+            map hasher(blake2_128_concat) T::AccountId => Option<T::BlockNumber>;
+        
+        /// Queued validators due for activation at a given block
+        ActivationsDue get(fn activations_due):
+            map hasher(twox_64_concat) T::BlockNumber => Vec<T::AccountId>;
+        
+        /// Block until which a validator is suspended from validating
+        SuspendedUntil get(fn suspended_until):
+            map hasher(blake2_128_concat) T::AccountId => Option<T::BlockNumber>;
+        
+        /// Suspended validators due to have their suspension lifted at a given block
+        SuspensionsEnding get(fn suspensions_ending):
+            map hasher(twox_64_concat) T::BlockNumber => Vec<T::AccountId>;
+        
+        /// Consecutive failed validations per validator, reset by a success
+        FailureStreaks get(fn failure_streak): map hasher(blake2_128_concat) T::AccountId => u32;
+        
+        /// Account holding trust admin rights alongside `AdminOrigin`
+        TrustAdmin get(fn trust_admin): Option<T::AccountId>;
+        
+        /// Account proposed as the next trust admin, awaiting its acceptance
+        PendingTrustAdmin get(fn pending_trust_admin): Option<T::AccountId>;
+        
+        /// Vote weight multiplier for each trust tier
+        TierWeights get(fn tier_weights): TierVoteWeights;
+        
+        /// Block each validator's trust was last exported cross-chain
+        LastExport get(fn last_export): map hasher(blake2_128_concat) T::AccountId => Option<T::BlockNumber>;
+        
+        /// Storage layout version of this pallet; a new chain starts on the current layout, while a
+        /// chain that never wrote it decodes as `Releases::V1` and is migrated
+        StorageVersion get(fn storage_version) build(|_| Releases::V3): Releases;
     }
 }
 
 decl_event!(
-    pub enum Event<T> where AccountId = <T as frame_system::Config>::AccountId {
-        /// Trust score updated for a validator
-        TrustScoreUpdated(AccountId, u32),
+    pub enum Event<T> where
+        AccountId = <T as frame_system::Config>::AccountId,
+        BlockNumber = <T as frame_system::Config>::BlockNumber,
+        Balance = BalanceOf<T>,
+    {
+        /// Trust score updated for a validator (at `EventScorePrecision`), with the change in
+        /// basis points when `EmitScoreDelta` is set
+        TrustScoreUpdated(AccountId, u32, Option<i32>),
         
         /// Validator added to trust system
         ValidatorAdded(AccountId),
         
+        /// Validator queued for activation at a block
+        ValidatorQueued(AccountId, BlockNumber),
+        
         /// Validator removed due to low trust score
         ValidatorRemoved(AccountId),
         
@@ -72,6 +599,75 @@ decl_event!(
         
         /// Validation failed, trust score decreased
         ValidationFailed(AccountId, u32),
+        
+        /// Validator removed, with final score (at `EventScorePrecision`), successes and failures
+        ValidatorRemovedWithStats(AccountId, u32, u32, u32),
+        
+        /// Validation round marked as a network-wide fault
+        NetworkFaultReported(u32),
+        
+        /// Penalty skipped because the round was faulted
+        PenaltyWaived(AccountId, u32),
+        
+        /// Validator evicted to make room for a new one
+        ValidatorEvicted(AccountId),
+        
+        /// Validator protection toggled
+        ValidatorProtectionSet(AccountId, bool),
+        
+        /// Season ended and scores were carried over (season index, validators reset)
+        SeasonReset(u32, u32),
+        
+        /// Validator reached a consecutive-success milestone
+        MilestoneReached(AccountId, u32),
+        
+        /// Validator moved between trust tiers
+        TierChanged(AccountId, ValidatorTier),
+        
+        /// Validator signalled liveness
+        Heartbeat(AccountId),
+        
+        /// Validator flagged for removal after `AutoExitPeriod` of silence
+        ValidatorAutoExited(AccountId),
+        
+        /// Validator disputed the penalty at a block, posting a bond
+        DisputeFiled(AccountId, BlockNumber, Balance),
+        
+        /// Dispute resolved (upheld or rejected) and its bond returned or slashed
+        DisputeResolved(AccountId, bool, Balance),
+        
+        /// Trust adjustments paused or resumed
+        TrustAdjustmentsPausedSet(bool),
+        
+        /// Tier vote weights reconfigured
+        TierWeightsSet(TierVoteWeights),
+        
+        /// Batch of validations reported for a validator (matched, total)
+        BatchValidationReported(AccountId, u32, u32),
+        
+        /// Validator paid its trust-proportional share of a reward pot
+        RewardDistributed(AccountId, Balance),
+        
+        /// Validator suspended from validating until a block
+        ValidatorSuspended(AccountId, BlockNumber),
+        
+        /// Validator's suspension ran out
+        SuspensionLifted(AccountId),
+        
+        /// Account proposed as the next trust admin
+        TrustAdminProposed(AccountId),
+        
+        /// Pending trust admin accepted and took over
+        TrustAdminAccepted(AccountId),
+        
+        /// Proposed trust admin withdrawn before acceptance
+        PendingTrustAdminCancelled(AccountId),
+        
+        /// Validator's trust data sent cross-chain
+        TrustExported(AccountId),
+        
+        /// Validator's counters cleared, with the successes and failures they held
+        ValidatorCountersReset(AccountId, u32, u32),
     }
 );
 
@@ -83,6 +679,44 @@ decl_error! {
         TrustScoreTooLow,
         /// Invalid trust score value
         InvalidTrustScore,
+        /// Reporter exceeded its trust updates for this block
+        ReporterRateLimited,
+        /// Removal would drop the active validator set below the minimum
+        TooFewValidators,
+        /// Validator set is at `MaxValidators`
+        TooManyValidators,
+        /// Validator set is full and every validator is protected
+        NoEvictableValidator,
+        /// Validator has no penalty to dispute
+        NothingToDispute,
+        /// Validator already has an open dispute
+        DisputeAlreadyOpen,
+        /// No open dispute for this validator
+        DisputeNotFound,
+        /// Caller cannot reserve the dispute bond
+        InsufficientBond,
+        /// Tier weights must not increase from a better tier to a worse one
+        NonMonotonicTierWeights,
+        /// Validator is already waiting in the onboarding queue
+        ValidatorPending,
+        /// Validator is already part of the trust system
+        ValidatorAlreadyExists,
+        /// Cross-chain sender refused the trust payload
+        ExportFailed,
+        /// Validator's trust was exported less than `ExportCooldown` blocks ago
+        ExportRateLimited,
+        /// Batch is empty, larger than `MaxBatchSize`, or matches more than it holds
+        InvalidBatch,
+        /// No validator is eligible for a share of the rewards
+        NoEligibleValidators,
+        /// Reward source could not pay a validator's share
+        RewardTransferFailed,
+        /// Trust adjustments are paused
+        AdjustmentsPaused,
+        /// No trust admin handover is in progress
+        NoPendingAdmin,
+        /// Only the proposed account can accept the trust admin role
+        NotPendingAdmin,
     }
 }
 
@@ -93,28 +727,152 @@ decl_module! {
         
         const MaxTrustScore: f32 = T::MaxTrustScore::get();
         const MinTrustScore: f32 = T::MinTrustScore::get();
+        const PenaltyCooldown: T::BlockNumber = T::PenaltyCooldown::get();
+        const RecoveryMultiplier: f32 = T::RecoveryMultiplier::get();
+        const MaxUpdatesPerReporterPerBlock: u32 = T::MaxUpdatesPerReporterPerBlock::get();
+        const ArchiveRetention: T::BlockNumber = T::ArchiveRetention::get();
+        const MinActiveValidators: u32 = T::MinActiveValidators::get();
+        const MaxValidators: u32 = T::MaxValidators::get();
+        const PromotionThreshold: u32 = T::PromotionThreshold::get();
+        const EventScorePrecision: u32 = T::EventScorePrecision::get();
+        
+        /// Translate legacy entries, backfilling `last_updated` so decay does not hit everyone after the upgrade
+        fn on_runtime_upgrade() -> Weight {
+            match Self::storage_version() {
+                Releases::V1 => Self::backfill_last_updated(),
+                Releases::V2 => Self::widen_block_numbers(),
+                Releases::V3 => T::DbWeight::get().reads(1),
+            }
+        }
+        
+        /// Record how many entries exist so `post_upgrade` can check none were dropped
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<(), &'static str> {
+            let entries = TrustScores::<T>::iter_keys().count() as u32;
+            frame_support::storage::unhashed::put(PRE_UPGRADE_ENTRIES_KEY, &entries);
+            Ok(())
+        }
+        
+        /// Every entry must survive the upgrade, decode as the current layout and carry a sane `last_updated`
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            let expected: u32 = frame_support::storage::unhashed::take(PRE_UPGRADE_ENTRIES_KEY)
+                .ok_or("pre_upgrade did not run")?;
+            ensure!(Self::storage_version() == Releases::V3, "storage version not bumped");
+            let now = <frame_system::Module<T>>::block_number();
+            let mut decoded = 0u32;
+            let mut flagged = 0u32;
+            for (_, trust_data) in TrustScores::<T>::iter() {
+                ensure!(trust_data.last_updated <= now, "last_updated in the future");
+                ensure!(trust_data.joined_at <= trust_data.last_updated, "joined_at after last_updated");
+                decoded += 1;
+                flagged += trust_data.flagged_for_removal as u32;
+            }
+            ensure!(decoded == expected, "trust entries lost in migration");
+            ensure!(flagged == Self::flagged_count(), "flagged count out of step");
+            Ok(())
+        }
+        
+        /// Reset per-reporter update counters and prune expired archive entries
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            let now = n;
+            ReporterUpdates::<T>::remove_all(None);
+            let pruned = Self::prune_archive(now);
+            let mut weight = T::DbWeight::get().reads_writes(1 + pruned, 2 + pruned);
+            
+            // Automatic score and standing changes wait out a pause like reported ones do, then
+            // catch up on the first block after it
+            let paused = Self::trust_adjustments_paused();
+            
+            let exit_period = T::AutoExitPeriod::get();
+            if !exit_period.is_zero() {
+                let scheduled = Self::next_auto_exit();
+                let (due, next) = Self::schedule_periodic(scheduled, now, exit_period, paused);
+                if scheduled != Some(next) {
+                    NextAutoExit::<T>::put(next);
+                }
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+                if due {
+                    let scanned = Self::auto_exit_inactive(now);
+                    weight = weight.saturating_add(T::DbWeight::get().reads_writes(1 + 2 * scanned, scanned));
+                }
+            }
+            
+            let lifted = Self::lift_suspensions(now);
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(lifted.0, lifted.1));
+            
+            let (scanned, activated) = Self::activate_pending(now);
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(scanned, 1 + 3 * activated));
+            
+            let season_length = T::SeasonLength::get();
+            if !season_length.is_zero() {
+                let scheduled = Self::next_season_reset();
+                let (due, next) = Self::schedule_periodic(scheduled, now, season_length, paused);
+                if scheduled != Some(next) {
+                    NextSeasonReset::<T>::put(next);
+                }
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+                if due {
+                    let reset = Self::reset_season();
+                    weight = weight.saturating_add(T::DbWeight::get().reads_writes(2 + reset, 3 + reset));
+                }
+            }
+            weight
+        }
+        
+        /// Write the top `DigestTopN` leaderboard entries (account, basis points) into the header
+        /// digest so light clients can check validator standing without state
+        fn on_finalize(_n: T::BlockNumber) {
+            let top_n = T::DigestTopN::get() as usize;
+            if top_n == 0 {
+                return;
+            }
+            let top: Vec<(T::AccountId, u32)> = Self::leaderboard().into_iter().take(top_n).collect();
+            <frame_system::Module<T>>::deposit_log(DigestItem::Consensus(TRUST_ENGINE_ID, top.encode()));
+        }
+        
+        /// Refresh the average and leaderboard with leftover block weight
+        fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+            if !Self::stats_dirty() {
+                return T::DbWeight::get().reads(1);
+            }
+            let needed = T::DbWeight::get().reads_writes(2 + Self::validator_list().len() as u64, 3);
+            if remaining_weight < needed {
+                return T::DbWeight::get().reads(2);
+            }
+            Self::refresh_stats();
+            needed
+        }
         
-        /// Initialize a validator in the trust system
+        /// Initialize a validator in the trust system, queueing it for `OnboardingDelay` blocks first
         #[weight = 10_000]
         pub fn initialize_validator(
             origin,
             validator: T::AccountId,
-        ) -> Result<(), Error<T>> {
-            let _who = ensure_signed(origin)?;
+        ) -> DispatchResult {
+            Self::ensure_admin(origin)?;
             
-            let initial_trust_data = NodeTrustData {
-                validator: validator.clone(),
-                trust_score: 0.5, // Start with neutral score
-                successful_validations: 0,
-                failed_validations: 0,
-                last_updated: <frame_system::Module<T>>::block_number().saturated_into::<u32>(),
-                flagged_for_removal: false,
-            };
-            
-            TrustScores::<T>::insert(&validator, &initial_trust_data);
-            ValidatorList::<T>::mutate(|list| list.push(validator.clone()));
+            ensure!(!TrustScores::<T>::contains_key(&validator), Error::<T>::ValidatorAlreadyExists);
+            ensure!(!PendingValidators::<T>::contains_key(&validator), Error::<T>::ValidatorPending);
+            // Queued validators hold a slot so activation never overfills the set
+            let pending = PendingValidators::<T>::iter().count();
+            if (Self::validator_list().len() + pending) as u32 >= T::MaxValidators::get() {
+                match T::OnFull::get() {
+                    FullSetPolicy::Reject => return Err(Error::<T>::TooManyValidators.into()),
+                    FullSetPolicy::EvictLowest => Self::evict_lowest()?,
+                }
+            }
             
-            Self::deposit_event(RawEvent::ValidatorAdded(validator));
+            let now = <frame_system::Module<T>>::block_number();
+            let delay = T::OnboardingDelay::get();
+            if delay.is_zero() {
+                Self::activate_validator(validator, now);
+            } else {
+                let activation = now.saturating_add(delay);
+                PendingValidators::<T>::insert(&validator, activation);
+                ActivationsDue::<T>::mutate(activation, |due| due.push(validator.clone()));
+                Self::deposit_event(RawEvent::ValidatorQueued(validator, activation));
+            }
             Ok(())
         }
         
@@ -124,8 +882,11 @@ decl_module! {
             origin,
             validator: T::AccountId,
             vote_matched: bool,  // True if node's vote matched network consensus
+            round: Option<u32>,  // Validation round the vote belongs to, if known
         ) -> Result<(), Error<T>> {
-            let _who = ensure_signed(origin)?;
+            let who = ensure_signed(origin)?;
+            
+            Self::note_reporter_updates(&who, 1)?;
             
             TrustScores::<T>::try_mutate(&validator, |trust_data_opt| {
                 let trust_data = trust_data_opt.as_mut().ok_or(Error::<T>::ValidatorNotFound)?;
@@ -134,63 +895,362 @@ decl_module! {
                 if trust_data.flagged_for_removal {
                     return Ok(());
                 }
+                Self::note_seen(&validator);
+                let previous_score = trust_data.trust_score;
                 
-                // Calculate new trust score based on vote match
-                if vote_matched {
-                    let increase = increase_fn(trust_data.trust_score);
-                    trust_data.trust_score = (trust_data.trust_score + increase).min(1.0);
-                    trust_data.successful_validations += 1;
-                } else {
-                    let decrease = decrease_fn(trust_data.trust_score);
-                    trust_data.trust_score = (trust_data.trust_score - decrease).max(0.0);
-                    trust_data.failed_validations += 1;
-                    
-                    // Flag for removal if trust score falls below 0.1
-                    if trust_data.trust_score < 0.1 {
-                        trust_data.flagged_for_removal = true;
-                        Self::deposit_event(RawEvent::ValidatorRemoved(validator.clone()));
+                // Report the vote but leave the score alone while adjustments are paused
+                if Self::trust_adjustments_paused() {
+                    let score = Self::event_score(trust_data.trust_score);
+                    if vote_matched {
+                        Self::deposit_event(RawEvent::ValidationSuccessful(validator.clone(), score));
+                    } else {
+                        Self::deposit_event(RawEvent::ValidationFailed(validator.clone(), score));
                     }
+                    return Ok(());
+                }
+                
+                // Disagreeing with consensus in a faulted round carries no penalty
+                if let Some(round) = round.filter(|r| !vote_matched && Self::faulted_rounds(r)) {
+                    Self::deposit_event(RawEvent::PenaltyWaived(validator.clone(), round));
+                    return Ok(());
                 }
                 
-                trust_data.last_updated = <frame_system::Module<T>>::block_number().saturated_into::<u32>();
+                let now = <frame_system::Module<T>>::block_number();
+                
+                // Settle inactivity decay before applying this update
+                Self::apply_half_life(trust_data, now);
+                
+                Self::apply_vote(&validator, trust_data, vote_matched, now);
+                
+                trust_data.last_updated = now;
+                Self::record_history(&validator, now, trust_data.trust_score);
+                Self::record_outcome(&validator, vote_matched);
+                // Average and leaderboard are recomputed in `on_idle`
+                StatsDirty::put(true);
                 
                 // Emit appropriate events
                 if vote_matched {
-                    Self::deposit_event(RawEvent::ValidationSuccessful(validator.clone(), trust_data.trust_score));
+                    Self::deposit_event(RawEvent::ValidationSuccessful(validator.clone(), Self::event_score(trust_data.trust_score)));
                 } else {
-                    Self::deposit_event(RawEvent::ValidationFailed(validator.clone(), trust_data.trust_score));
+                    Self::deposit_event(RawEvent::ValidationFailed(validator.clone(), Self::event_score(trust_data.trust_score)));
                 }
-                Self::deposit_event(RawEvent::TrustScoreUpdated(validator.clone(), trust_data.trust_score));
+                Self::deposit_event(RawEvent::TrustScoreUpdated(
+                    validator.clone(),
+                    Self::event_score(trust_data.trust_score),
+                    Self::score_delta(previous_score, trust_data.trust_score),
+                ));
                 
                 Ok(())
             })
         }
-    }
-
-    #[weight = 10_000]
-    pub fn cleanup_validators(origin) -> Result<(), Error<T>> {
-        let _who = ensure_signed(origin)?;
         
-        let validators_to_remove: Vec<T::AccountId> = ValidatorList::<T>::get()
-            .into_iter()
-            .filter(|validator| {
-                Self::trust_scores(validator)
-                    .map(|data| data.flagged_for_removal)
-                    .unwrap_or(false)
+        /// Record a batch of `total` validations of which `matched` agreed with consensus. The
+        /// votes are applied evenly interleaved, as if reported one by one within this block.
+        #[weight = 5_000u64.saturating_mul(*total as u64)]
+        pub fn update_trust_score_batch(
+            origin,
+            validator: T::AccountId,
+            matched: u32,
+            total: u32,
+        ) -> Result<(), Error<T>> {
+            let who = ensure_signed(origin)?;
+            
+            ensure!(
+                total > 0 && matched <= total && total <= T::MaxBatchSize::get(),
+                Error::<T>::InvalidBatch
+            );
+            // Each validation in the batch counts as one update against the throttle
+            Self::note_reporter_updates(&who, total)?;
+            
+            TrustScores::<T>::try_mutate(&validator, |trust_data_opt| {
+                let trust_data = trust_data_opt.as_mut().ok_or(Error::<T>::ValidatorNotFound)?;
+                
+                if trust_data.flagged_for_removal {
+                    return Ok(());
+                }
+                Self::note_seen(&validator);
+                Self::deposit_event(RawEvent::BatchValidationReported(validator.clone(), matched, total));
+                if Self::trust_adjustments_paused() {
+                    return Ok(());
+                }
+                let previous_score = trust_data.trust_score;
+                let now = <frame_system::Module<T>>::block_number();
+                Self::apply_half_life(trust_data, now);
+                
+                for i in 0..total as u64 {
+                    // Successes are spread through the batch so no run of failures escalates unduly
+                    let vote_matched = (i + 1) * matched as u64 / total as u64 > i * matched as u64 / total as u64;
+                    Self::apply_vote(&validator, trust_data, vote_matched, now);
+                    Self::record_outcome(&validator, vote_matched);
+                    if trust_data.flagged_for_removal {
+                        break;
+                    }
+                }
+                
+                trust_data.last_updated = now;
+                Self::record_history(&validator, now, trust_data.trust_score);
+                StatsDirty::put(true);
+                Self::deposit_event(RawEvent::TrustScoreUpdated(
+                    validator.clone(),
+                    Self::event_score(trust_data.trust_score),
+                    Self::score_delta(previous_score, trust_data.trust_score),
+                ));
+                
+                Ok(())
             })
-            .collect();
+        }
         
-        for validator in validators_to_remove {
-            Self::remove_validator(&validator);
+        /// Mark a validation round as a network-wide fault so its mismatches are not penalized
+        #[weight = 10_000]
+        pub fn report_network_fault(origin, block_ref: u32) {
+            Self::ensure_admin(origin)?;
+            
+            FaultedRounds::insert(block_ref, true);
+            Self::deposit_event(RawEvent::NetworkFaultReported(block_ref));
         }
         
-        Ok(())
-    }
-}
-
-#[inline(always)]
-fn increase_fn(trust_score: f32) -> f32 {
-    0.001_f32 * (0.5_f32 * E.powf(2.5_f32 * trust_score))
+        /// Signal liveness so the validator is not auto-exited while it has nothing to validate
+        #[weight = 5_000]
+        pub fn heartbeat(origin) {
+            let who = ensure_signed(origin)?;
+            
+            let active = Self::trust_scores(&who).map(|data| !data.flagged_for_removal).unwrap_or(false);
+            ensure!(active, Error::<T>::ValidatorNotFound);
+            Self::note_seen(&who);
+            Self::deposit_event(RawEvent::Heartbeat(who));
+        }
+        
+        /// Dispute the caller's most recent penalty, reserving `DisputeBond`
+        #[weight = 10_000]
+        pub fn file_dispute(origin) {
+            let who = ensure_signed(origin)?;
+            
+            ensure!(!Disputes::<T>::contains_key(&who), Error::<T>::DisputeAlreadyOpen);
+            let penalized_at = Self::trust_scores(&who)
+                .ok_or(Error::<T>::ValidatorNotFound)?
+                .last_penalty_block
+                .ok_or(Error::<T>::NothingToDispute)?;
+            
+            let bond = T::DisputeBond::get();
+            T::Currency::reserve(&who, bond).map_err(|_| Error::<T>::InsufficientBond)?;
+            Disputes::<T>::insert(&who, (penalized_at, bond));
+            Self::deposit_event(RawEvent::DisputeFiled(who, penalized_at, bond));
+        }
+        
+        /// Resolve an open dispute, returning the bond if upheld and slashing it otherwise
+        #[weight = 10_000]
+        pub fn resolve_dispute(origin, validator: T::AccountId, upheld: bool) {
+            Self::ensure_admin(origin)?;
+            
+            let (_, bond) = Disputes::<T>::take(&validator).ok_or(Error::<T>::DisputeNotFound)?;
+            if upheld {
+                T::Currency::unreserve(&validator, bond);
+            } else {
+                let _ = T::Currency::slash_reserved(&validator, bond);
+            }
+            Self::deposit_event(RawEvent::DisputeResolved(validator, upheld, bond));
+        }
+        
+        /// Pause or resume trust score adjustments, e.g. around a runtime upgrade. Time spent
+        /// paused does not count towards inactivity decay.
+        #[weight = 10_000]
+        pub fn set_trust_adjustments_paused(origin, paused: bool) {
+            Self::ensure_admin(origin)?;
+            
+            let now = <frame_system::Module<T>>::block_number();
+            if paused && !Self::trust_adjustments_paused() {
+                PausedSince::<T>::put(now);
+            } else if !paused {
+                if let Some(since) = PausedSince::<T>::take() {
+                    Self::shift_decay_clocks(now.saturating_sub(since), now);
+                }
+            }
+            TrustAdjustmentsPaused::put(paused);
+            Self::deposit_event(RawEvent::TrustAdjustmentsPausedSet(paused));
+        }
+        
+        /// Reconfigure the vote weight of each trust tier
+        #[weight = 10_000]
+        pub fn set_tier_weights(origin, weights: TierVoteWeights) {
+            Self::ensure_admin(origin)?;
+            
+            ensure!(weights.is_monotonic(), Error::<T>::NonMonotonicTierWeights);
+            TierWeights::put(weights);
+            Self::deposit_event(RawEvent::TierWeightsSet(weights));
+        }
+        
+        /// Split `pot` from `RewardSource` among validators able to validate, in proportion to
+        /// their trust. The rounding remainder goes to the largest share.
+        #[weight = 50_000]
+        pub fn distribute_rewards(origin, pot: BalanceOf<T>) {
+            Self::ensure_admin(origin)?;
+            
+            let shares = Self::reward_shares(pot);
+            ensure!(!shares.is_empty(), Error::<T>::NoEligibleValidators);
+            let source = T::RewardSource::get();
+            // Pay every share or none, so a failed transfer leaves no validator paid early
+            with_transaction(|| {
+                for (validator, share) in shares {
+                    if T::Currency::transfer(&source, &validator, share, ExistenceRequirement::AllowDeath).is_err() {
+                        return TransactionOutcome::Rollback(Err(Error::<T>::RewardTransferFailed.into()));
+                    }
+                    Self::deposit_event(RawEvent::RewardDistributed(validator, share));
+                }
+                TransactionOutcome::Commit(Ok(()))
+            })?;
+        }
+        
+        /// Propose a new trust admin; the role only moves once that account accepts
+        #[weight = 10_000]
+        pub fn propose_new_trust_admin(origin, new: T::AccountId) {
+            Self::ensure_admin(origin)?;
+            
+            PendingTrustAdmin::<T>::put(&new);
+            Self::deposit_event(RawEvent::TrustAdminProposed(new));
+        }
+        
+        /// Take over as trust admin, signed by the proposed account
+        #[weight = 10_000]
+        pub fn accept_trust_admin(origin) {
+            let who = ensure_signed(origin)?;
+            
+            let pending = Self::pending_trust_admin().ok_or(Error::<T>::NoPendingAdmin)?;
+            ensure!(pending == who, Error::<T>::NotPendingAdmin);
+            PendingTrustAdmin::<T>::kill();
+            TrustAdmin::<T>::put(&who);
+            Self::deposit_event(RawEvent::TrustAdminAccepted(who));
+        }
+        
+        /// Withdraw a proposed trust admin before it accepts
+        #[weight = 10_000]
+        pub fn cancel_pending_admin(origin) {
+            Self::ensure_admin(origin)?;
+            
+            let pending = PendingTrustAdmin::<T>::take().ok_or(Error::<T>::NoPendingAdmin)?;
+            Self::deposit_event(RawEvent::PendingTrustAdminCancelled(pending));
+        }
+        
+        /// Send a validator's trust standing to another chain through `XcmSender`. Callable by
+        /// the admin or the validator itself, at most once per `ExportCooldown` per validator.
+        #[weight = 20_000]
+        pub fn export_trust_xcm(
+            origin,
+            validator: T::AccountId,
+            dest: <T::XcmSender as XcmSender>::Destination,
+        ) {
+            if Self::ensure_admin(origin.clone()).is_err() {
+                let who = ensure_signed(origin)?;
+                ensure!(who == validator, DispatchError::BadOrigin);
+            }
+            
+            let now = <frame_system::Module<T>>::block_number();
+            if let Some(last) = Self::last_export(&validator) {
+                ensure!(now.saturating_sub(last) >= T::ExportCooldown::get(), Error::<T>::ExportRateLimited);
+            }
+            let payload = Self::trust_export(&validator).ok_or(Error::<T>::ValidatorNotFound)?;
+            T::XcmSender::send(dest, payload.encode()).map_err(|_| Error::<T>::ExportFailed)?;
+            LastExport::<T>::insert(&validator, now);
+            Self::deposit_event(RawEvent::TrustExported(validator));
+        }
+        
+        /// Zero a validator's validation counters and streak, leaving its score and flags as they are
+        #[weight = 10_000]
+        pub fn reset_validator_counters(origin, validator: T::AccountId) {
+            Self::ensure_admin(origin)?;
+            
+            let (successes, failures) = TrustScores::<T>::try_mutate(&validator, |trust_data_opt| {
+                let trust_data = trust_data_opt.as_mut().ok_or(Error::<T>::ValidatorNotFound)?;
+                let counters = (trust_data.successful_validations, trust_data.failed_validations);
+                trust_data.successful_validations = 0;
+                trust_data.failed_validations = 0;
+                trust_data.success_streak = 0;
+                // Unchanged score, but the reset is still visible in the audit trail
+                Self::record_history(&validator, <frame_system::Module<T>>::block_number(), trust_data.trust_score);
+                Ok::<(u32, u32), Error<T>>(counters)
+            })?;
+            FailureStreaks::<T>::remove(&validator);
+            
+            Self::deposit_event(RawEvent::ValidatorCountersReset(validator, successes, failures));
+        }
+        
+        /// Protect a validator from eviction, or lift its protection
+        #[weight = 10_000]
+        pub fn set_validator_protection(origin, validator: T::AccountId, protected: bool) {
+            Self::ensure_admin(origin)?;
+            
+            if protected {
+                ProtectedValidators::<T>::insert(&validator, true);
+            } else {
+                ProtectedValidators::<T>::remove(&validator);
+            }
+            Self::deposit_event(RawEvent::ValidatorProtectionSet(validator, protected));
+        }
+        
+        /// Apply accumulated inactivity decay to a validator's stored score
+        #[weight = 5_000]
+        pub fn apply_decay(origin, validator: T::AccountId) {
+            let _who = ensure_signed(origin)?;
+            
+            ensure!(!Self::trust_adjustments_paused(), Error::<T>::AdjustmentsPaused);
+            let (previous, score) = TrustScores::<T>::try_mutate(&validator, |trust_data_opt| {
+                let trust_data = trust_data_opt.as_mut().ok_or(Error::<T>::ValidatorNotFound)?;
+                let previous = trust_data.trust_score;
+                let now = <frame_system::Module<T>>::block_number();
+                Self::apply_half_life(trust_data, now);
+                trust_data.last_updated = now;
+                Self::record_history(&validator, now, trust_data.trust_score);
+                if trust_data.trust_score < REMOVAL_THRESHOLD && !trust_data.flagged_for_removal {
+                    Self::flag_for_removal(trust_data, RemovalReason::LowTrust);
+                    Self::deposit_event(RawEvent::ValidatorRemoved(validator.clone()));
+                }
+                Ok::<(f32, f32), Error<T>>((previous, trust_data.trust_score))
+            })?;
+            
+            StatsDirty::put(true);
+            Self::deposit_event(RawEvent::TrustScoreUpdated(
+                validator,
+                Self::event_score(score),
+                Self::score_delta(previous, score),
+            ));
+        }
+        
+        #[weight = 10_000]
+        pub fn cleanup_validators(origin) -> DispatchResultWithPostInfo {
+            let _who = ensure_signed(origin)?;
+            
+            // Nothing to clean up: free and a single read, so automation can call speculatively
+            if Self::flagged_count() == 0 {
+                return Ok(PostDispatchInfo {
+                    actual_weight: Some(T::DbWeight::get().reads(1)),
+                    pays_fee: Pays::No,
+                });
+            }
+            
+            let validators_to_remove: Vec<T::AccountId> = ValidatorList::<T>::get()
+                .into_iter()
+                .filter(|validator| {
+                    Self::trust_scores(validator)
+                        .map(|data| data.flagged_for_removal)
+                        .unwrap_or(false)
+                })
+                .collect();
+            
+            // Refuse the whole cleanup rather than leaving consensus short of validators
+            let remaining = Self::validator_list().len().saturating_sub(validators_to_remove.len());
+            Self::ensure_min_active(remaining)?;
+            
+            for validator in validators_to_remove {
+                Self::remove_validator(&validator)?;
+            }
+            
+            Ok(().into())
+        }
+    }
+}
+
+#[inline(always)]
+fn increase_fn(trust_score: f32) -> f32 {
+    0.001_f32 * (0.5_f32 * E.powf(2.5_f32 * trust_score))
 }
 
 #[inline(always)]
@@ -198,42 +1258,1009 @@ fn decrease_fn(trust_score: f32) -> f32 {
     0.001_f32 * (1.0_f32 - (1.0_f32 / (1.0_f32 - 2.5_f32 * trust_score)))
 }
 
+/// Halve `bps` once per full `half_life` of `elapsed` blocks, stepping linearly within a partial
+/// half-life. Integer-only so every node computes the same result.
+fn half_life_decay(bps: u32, elapsed: u32, half_life: u32) -> u32 {
+    if half_life == 0 {
+        return bps;
+    }
+    let halvings = elapsed / half_life;
+    if halvings >= 32 {
+        return 0;
+    }
+    let halved = bps >> halvings;
+    let partial = (elapsed % half_life) as u64;
+    let lost = (halved as u64 * partial / (2 * half_life as u64)) as u32;
+    halved - lost
+}
+
+/// Convert a `Permill` to a fraction in `[0, 1]`
+#[inline(always)]
+fn permill_to_f32(value: Permill) -> f32 {
+    value.deconstruct() as f32 / 1_000_000_f32
+}
+
+/// Convert a trust score in `[0, 1]` to basis points
+#[inline(always)]
+fn score_to_bps(trust_score: f32) -> u32 {
+    (trust_score.max(0.0).min(1.0) * 10_000_f32) as u32
+}
+
 impl<T: Config> Module<T> {
-    /// Check if validator can participate in validation
-    fn remove_validator(validator: &T::AccountId) {
-        if let Some(trust_data) = Self::trust_scores(validator) {
-            if trust_data.flagged_for_removal {
-                TrustScores::<T>::remove(validator);
-                ValidatorList::<T>::mutate(|list| list.retain(|v| v != validator));
+    /// Downscale a trust score in `[0, 1]` to `EventScorePrecision` steps for event emission
+    fn event_score(trust_score: f32) -> u32 {
+        (trust_score.max(0.0).min(1.0) * T::EventScorePrecision::get() as f32) as u32
+    }
+    
+    /// Signed change in basis points between two scores, when `EmitScoreDelta` is enabled;
+    /// taken on the basis-point values so successive deltas sum exactly to the stored change
+    fn score_delta(previous: f32, current: f32) -> Option<i32> {
+        if !T::EmitScoreDelta::get() {
+            return None;
+        }
+        Some(score_to_bps(current) as i32 - score_to_bps(previous) as i32)
+    }
+    
+    /// Scale `base` inversely with the validator's trust (half the trust, twice the slash),
+    /// clamped to `MaxSlashMultiple` times `base`; unknown validators pay the maximum
+    pub fn slash_amount(validator: &T::AccountId, base: BalanceOf<T>) -> BalanceOf<T> {
+        let max_multiple = T::MaxSlashMultiple::get().max(1) as u128;
+        let base: u128 = base.saturated_into();
+        let ceiling = base.saturating_mul(max_multiple);
+        let scaled = match Self::get_trust_score(validator) {
+            Some(score) => {
+                let bps = score_to_bps(score).max(1) as u128;
+                base.saturating_mul(10_000) / bps
+            }
+            None => ceiling,
+        };
+        scaled.min(ceiling).saturated_into()
+    }
+    
+    /// Starting score for a new validator: bootstrapped reputation clamped into range, else `InitialTrustScore`
+    fn starting_score(validator: &T::AccountId) -> f32 {
+        T::ReputationBootstrap::starting_score(validator)
+            .filter(|score| !score.is_nan())
+            .map(|score| score.max(0.0).min(1.0))
+            .unwrap_or_else(T::InitialTrustScore::get)
+    }
+    
+    /// Evict the lowest-scoring unprotected validator
+    fn evict_lowest() -> Result<(), Error<T>> {
+        let lowest = Self::validator_list()
+            .into_iter()
+            .filter(|validator| !Self::protected_validators(validator))
+            .filter_map(|validator| Self::get_trust_score(&validator).map(|score| (validator, score)))
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(core::cmp::Ordering::Equal))
+            .map(|(validator, _)| validator)
+            .ok_or(Error::<T>::NoEvictableValidator)?;
+        
+        let trust_data = Self::retire_trust_data(&lowest);
+        ValidatorList::<T>::mutate(|list| list.retain(|v| v != &lowest));
+        StatsDirty::put(true);
+        if let Some(data) = trust_data {
+            Self::deposit_event(RawEvent::ValidatorRemovedWithStats(
+                lowest.clone(),
+                Self::event_score(data.trust_score),
+                data.successful_validations,
+                data.failed_validations,
+            ));
+        }
+        Self::deposit_event(RawEvent::ValidatorEvicted(lowest));
+        Ok(())
+    }
+    
+    /// Snapshot the ending season and pull unprotected scores toward `InitialTrustScore`,
+    /// returning how many validators were reset
+    fn reset_season() -> u64 {
+        let season = Self::current_season();
+        let now = <frame_system::Module<T>>::block_number();
+        let initial = T::InitialTrustScore::get();
+        let carryover = permill_to_f32(T::CarryoverFactor::get());
+        
+        let mut snapshot = Vec::new();
+        let mut reset = 0u64;
+        // Canonical order keeps snapshots reproducible across nodes and insertion histories
+        for validator in Self::validators_canonical() {
+            let protected = Self::protected_validators(&validator);
+            TrustScores::<T>::mutate(&validator, |trust_data_opt| {
+                if let Some(trust_data) = trust_data_opt {
+                    snapshot.push((validator.clone(), score_to_bps(trust_data.trust_score)));
+                    if !protected {
+                        trust_data.trust_score = initial + (trust_data.trust_score - initial) * carryover;
+                        trust_data.raw_score = trust_data.trust_score;
+                        Self::record_history(&validator, now, trust_data.trust_score);
+                        reset += 1;
+                    }
+                }
+            });
+        }
+        
+        SeasonSnapshots::<T>::insert(season, snapshot);
+        CurrentSeason::put(season.saturating_add(1));
+        StatsDirty::put(true);
+        Self::deposit_event(RawEvent::SeasonReset(season, reset as u32));
+        reset
+    }
+    
+    /// Median of current validator scores, or `None` for an empty set; unlike the mean it is
+    /// not dragged down by a few near-zero outliers
+    pub fn median_trust_score() -> Option<f32> {
+        let mut scores: Vec<f32> = Self::validator_list()
+            .iter()
+            .filter_map(Self::get_trust_score)
+            .collect();
+        if scores.is_empty() {
+            return None;
+        }
+        scores.sort_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+        let mid = scores.len() / 2;
+        if scores.len() % 2 == 0 {
+            Some((scores[mid - 1] + scores[mid]) / 2.0)
+        } else {
+            Some(scores[mid])
+        }
+    }
+    
+    /// Mean and median trust score in basis points, for comparing the two in monitoring
+    pub fn trust_score_summary() -> (u32, Option<u32>) {
+        (score_to_bps(Self::average_trust_score()), Self::median_trust_score().map(score_to_bps))
+    }
+    
+    /// Append a trust change to the validator's audit history, keeping at most `MaxHistoryLength`
+    fn record_history(validator: &T::AccountId, now: T::BlockNumber, trust_score: f32) {
+        TrustHistory::<T>::mutate(validator, |history| {
+            history.push((now, score_to_bps(trust_score)));
+            let max = T::MaxHistoryLength::get() as usize;
+            if history.len() > max {
+                let excess = history.len() - max;
+                history.drain(..excess);
+            }
+        });
+    }
+    
+    /// Trust changes of every validator within `[from_block, to_block]`, sorted by block, as
+    /// the `page`-th slice of `AUDIT_PAGE_SIZE` entries
+    pub fn trust_changes_between(
+        from_block: T::BlockNumber,
+        to_block: T::BlockNumber,
+        page: u32,
+    ) -> Vec<(T::AccountId, T::BlockNumber, u32)> {
+        let mut changes: Vec<(T::AccountId, T::BlockNumber, u32)> = TrustHistory::<T>::iter()
+            .flat_map(|(validator, history)| {
+                history
+                    .into_iter()
+                    .filter(|(block, _)| *block >= from_block && *block <= to_block)
+                    .map(move |(block, score)| (validator.clone(), block, score))
+            })
+            .collect();
+        changes.sort_by_key(|(_, block, _)| *block);
+        changes
+            .into_iter()
+            .skip(page.saturating_mul(AUDIT_PAGE_SIZE) as usize)
+            .take(AUDIT_PAGE_SIZE as usize)
+            .collect()
+    }
+    
+    /// Translate `Releases::V1` entries straight into the current layout, stamping the current block
+    /// on entries with no meaningful `last_updated` (zero or in the future), and bump the storage
+    /// version, returning the weight used
+    fn backfill_last_updated() -> Weight {
+        let now = <frame_system::Module<T>>::block_number();
+        let mut translated = 0u64;
+        let mut flagged = 0u32;
+        TrustScores::<T>::translate::<OldNodeTrustData<T::AccountId>, _>(|_, old| {
+            translated += 1;
+            flagged += old.flagged_for_removal as u32;
+            let recorded: T::BlockNumber = old.last_updated.into();
+            let last_updated = if recorded.is_zero() || recorded > now { now } else { recorded };
+            Some(NodeTrustData {
+                validator: old.validator,
+                trust_score: old.trust_score,
+                raw_score: old.trust_score,
+                successful_validations: old.successful_validations,
+                failed_validations: old.failed_validations,
+                success_streak: 0,
+                last_updated,
+                // Tenure was not tracked; count it from the last recorded activity
+                joined_at: last_updated,
+                last_penalty_block: None,
+                flagged_for_removal: old.flagged_for_removal,
+                tier: ValidatorTier::Trusted,
+                // Low trust was the only way to be flagged under V1
+                removal_reason: if old.flagged_for_removal { Some(RemovalReason::LowTrust) } else { None },
+            })
+        });
+        FlaggedCount::put(flagged);
+        StorageVersion::put(Releases::V3);
+        T::DbWeight::get().reads_writes(2 + translated, 2 + translated)
+    }
+    
+    /// Re-encode every `Releases::V2` value holding a `u32` block number at the runtime's block
+    /// number width and bump the storage version, returning the weight used
+    fn widen_block_numbers() -> Weight {
+        let mut translated = 0u64;
+        let mut flagged = 0u32;
+        TrustScores::<T>::translate::<V2NodeTrustData<T::AccountId>, _>(|_, old| {
+            translated += 1;
+            flagged += old.flagged_for_removal as u32;
+            Some(old.upgrade())
+        });
+        RemovedValidators::<T>::translate::<(V2NodeTrustData<T::AccountId>, u32), _>(|_, (old, removed_at)| {
+            translated += 1;
+            Some((old.upgrade(), removed_at.into()))
+        });
+        TrustHistory::<T>::translate::<Vec<(u32, u32)>, _>(|_, history| {
+            translated += 1;
+            Some(history.into_iter().map(|(block, bps)| (block.into(), bps)).collect())
+        });
+        LastSeen::<T>::translate::<u32, _>(|_, block| {
+            translated += 1;
+            Some(block.into())
+        });
+        Disputes::<T>::translate::<(u32, BalanceOf<T>), _>(|_, (block, bond)| {
+            translated += 1;
+            Some((block.into(), bond))
+        });
+        
+        // Expiry blocks are hashed into the key, so entries move to keys of the new width
+        let expiries: Vec<(u32, Vec<T::AccountId>)> =
+            storage_key_iter::<u32, Vec<T::AccountId>, Twox64Concat>(b"TrustScore", b"ArchiveExpiry")
+                .drain()
+                .collect();
+        for (block, validators) in expiries {
+            translated += 1;
+            ArchiveExpiry::<T>::insert(T::BlockNumber::from(block), validators);
+        }
+        
+        FlaggedCount::put(flagged);
+        StorageVersion::put(Releases::V3);
+        T::DbWeight::get().reads_writes(1 + translated, 2 + translated)
+    }
+    
+    /// Append a validation outcome, keeping the last `HealthWindow`
+    fn record_outcome(validator: &T::AccountId, success: bool) {
+        RecentOutcomes::<T>::mutate(validator, |outcomes| {
+            outcomes.push(success);
+            let max = T::HealthWindow::get() as usize;
+            if outcomes.len() > max {
+                let excess = outcomes.len() - max;
+                outcomes.drain(..excess);
+            }
+        });
+    }
+    
+    /// Combined health in basis points, or `None` for an unknown validator:
+    ///
+    /// `(wt * trust + ws * success + wr * recency) / (wt + ws + wr)`
+    ///
+    /// where `trust` is the score in basis points, `success` the share of the last `HealthWindow`
+    /// validations that matched consensus (5_000 with none yet), and `recency` falls linearly
+    /// from 10_000 at the last sighting to 0 after `HealthRecencyPeriod` blocks. Integer-only.
+    pub fn health_score(validator: &T::AccountId) -> Option<u32> {
+        let data = Self::trust_scores(validator)?;
+        let now = <frame_system::Module<T>>::block_number();
+        
+        let trust = score_to_bps(data.trust_score) as u64;
+        
+        // No track record yet counts as middling
+        let success = Self::recent_success_rate(validator).unwrap_or(5_000) as u64;
+        
+        let period = T::HealthRecencyPeriod::get().saturated_into::<u64>();
+        let silent = now
+            .saturating_sub(Self::last_seen(validator).unwrap_or(data.joined_at))
+            .saturated_into::<u64>();
+        let recency = if period == 0 {
+            10_000
+        } else {
+            10_000 - silent.min(period) * 10_000 / period
+        };
+        
+        let (wt, ws, wr) = (
+            T::HealthTrustWeight::get() as u64,
+            T::HealthSuccessWeight::get() as u64,
+            T::HealthRecencyWeight::get() as u64,
+        );
+        let total = wt + ws + wr;
+        if total == 0 {
+            return Some(trust as u32);
+        }
+        Some(((wt * trust + ws * success + wr * recency) / total) as u32)
+    }
+    
+    /// Accept `AdminOrigin`, or a signed origin from the current trust admin
+    fn ensure_admin(origin: T::Origin) -> Result<(), DispatchError> {
+        match T::AdminOrigin::try_origin(origin) {
+            Ok(_) => Ok(()),
+            Err(origin) => {
+                let who = ensure_signed(origin)?;
+                ensure!(Self::trust_admin().as_ref() == Some(&who), DispatchError::BadOrigin);
+                Ok(())
+            }
+        }
+    }
+    
+    /// Record that a validator was active at the current block
+    fn note_seen(validator: &T::AccountId) {
+        let now = <frame_system::Module<T>>::block_number();
+        LastSeen::<T>::insert(validator, now);
+    }
+    
+    /// Add a validator to the active set with fresh trust data
+    fn activate_validator(validator: T::AccountId, now: T::BlockNumber) {
+        let starting_score = Self::starting_score(&validator);
+        let initial_trust_data = NodeTrustData {
+            validator: validator.clone(),
+            trust_score: starting_score,
+            raw_score: starting_score,
+            successful_validations: 0,
+            failed_validations: 0,
+            success_streak: 0,
+            last_updated: now,
+            joined_at: now,
+            last_penalty_block: None,
+            flagged_for_removal: false,
+            tier: ValidatorTier::Trusted,
+            removal_reason: None,
+        };
+        
+        TrustScores::<T>::insert(&validator, &initial_trust_data);
+        ValidatorList::<T>::mutate(|list| list.push(validator.clone()));
+        StatsDirty::put(true);
+        
+        Self::deposit_event(RawEvent::ValidatorAdded(validator));
+    }
+    
+    /// Promote the queued validators due for activation at `now`, returning (reads, activated)
+    fn activate_pending(now: T::BlockNumber) -> (u64, u64) {
+        let due = ActivationsDue::<T>::take(now);
+        let mut activated = 0u64;
+        for validator in due.iter() {
+            if PendingValidators::<T>::take(validator).is_some() {
+                Self::activate_validator(validator.clone(), now);
+                activated += 1;
+            }
+        }
+        (1 + due.len() as u64, activated)
+    }
+    
+    /// Current cross-chain payload for a validator
+    pub fn trust_export(validator: &T::AccountId) -> Option<TrustExport<T::AccountId, T::BlockNumber>> {
+        Self::trust_scores(validator).map(|data| TrustExport::V1 {
+            validator: data.validator,
+            trust_score: score_to_bps(data.trust_score),
+            successful_validations: data.successful_validations,
+            failed_validations: data.failed_validations,
+            tier: data.tier,
+            flagged_for_removal: data.flagged_for_removal,
+            last_updated: data.last_updated,
+        })
+    }
+    
+    /// Each eligible validator's share of `pot`, proportional to trust in basis points and
+    /// summing exactly to `pot`
+    pub fn reward_shares(pot: BalanceOf<T>) -> Vec<(T::AccountId, BalanceOf<T>)> {
+        let weights: Vec<(T::AccountId, u128)> = Self::validator_list()
+            .into_iter()
+            .filter(Self::can_validate)
+            .filter_map(|validator| {
+                let bps = Self::get_trust_score(&validator).map(score_to_bps)? as u128;
+                Some((validator, bps))
+            })
+            .filter(|(_, bps)| *bps > 0)
+            .collect();
+        let total: u128 = weights.iter().map(|(_, bps)| *bps).sum();
+        if total == 0 {
+            return Vec::new();
+        }
+        
+        let pot_units: u128 = pot.saturated_into();
+        let mut shares: Vec<(T::AccountId, u128)> = weights
+            .into_iter()
+            .map(|(validator, bps)| (validator, pot_units.saturating_mul(bps) / total))
+            .collect();
+        let paid: u128 = shares.iter().map(|(_, share)| *share).sum();
+        if let Some(largest) = shares.iter_mut().max_by_key(|(_, share)| *share) {
+            largest.1 = largest.1.saturating_add(pot_units.saturating_sub(paid));
+        }
+        shares
+            .into_iter()
+            .filter(|(_, share)| *share > 0)
+            .map(|(validator, share)| (validator, share.saturated_into()))
+            .collect()
+    }
+    
+    /// Count a failure towards the validator's streak and, when `SuspendOnFailure` is set,
+    /// suspend it for `SuspensionLength` per consecutive failure, up to `MaxSuspensionLength`
+    fn suspend_after_failure(validator: &T::AccountId, now: T::BlockNumber) {
+        let streak = FailureStreaks::<T>::mutate(validator, |streak| {
+            *streak = streak.saturating_add(1);
+            *streak
+        });
+        if !T::SuspendOnFailure::get() {
+            return;
+        }
+        let length = T::SuspensionLength::get()
+            .saturating_mul(streak.into())
+            .min(T::MaxSuspensionLength::get());
+        let until = now.saturating_add(length);
+        SuspendedUntil::<T>::insert(validator, until);
+        // Lift no sooner than the next block, which `on_initialize` still visits
+        SuspensionsEnding::<T>::mutate(until.max(now.saturating_add(One::one())), |due| {
+            if !due.contains(validator) {
+                due.push(validator.clone());
+            }
+        });
+        Self::deposit_event(RawEvent::ValidatorSuspended(validator.clone(), until));
+    }
+    
+    /// Clear the suspensions due to end at `now`, skipping validators whose suspension was since
+    /// extended or cleared, returning (reads, writes)
+    fn lift_suspensions(now: T::BlockNumber) -> (u64, u64) {
+        let due = SuspensionsEnding::<T>::take(now);
+        let mut lifted = 0u64;
+        for validator in due.iter() {
+            if Self::suspended_until(validator).map_or(false, |until| until <= now) {
+                SuspendedUntil::<T>::remove(validator);
+                Self::deposit_event(RawEvent::SuspensionLifted(validator.clone()));
+                lifted += 1;
+            }
+        }
+        (1 + due.len() as u64, 1 + lifted)
+    }
+    
+    /// Whether a validator is active, unflagged and trusted enough to take part in validation.
+    /// Validators still in the onboarding queue or serving a suspension cannot validate.
+    pub fn can_validate(validator: &T::AccountId) -> bool {
+        if PendingValidators::<T>::contains_key(validator) {
+            return false;
+        }
+        let now = <frame_system::Module<T>>::block_number();
+        if Self::suspended_until(validator).map_or(false, |until| now < until) {
+            return false;
+        }
+        match Self::trust_scores(validator) {
+            Some(data) => !data.flagged_for_removal && data.trust_score >= Self::min_validation_trust(),
+            None => false,
+        }
+    }
+    
+    /// Flag unprotected validators silent for `AutoExitPeriod`, returning how many were scanned
+    fn auto_exit_inactive(now: T::BlockNumber) -> u64 {
+        let period = T::AutoExitPeriod::get();
+        let mut scanned = 0u64;
+        for validator in Self::validator_list() {
+            scanned += 1;
+            if Self::protected_validators(&validator) {
+                continue;
+            }
+            let last_seen = Self::last_seen(&validator);
+            TrustScores::<T>::mutate(&validator, |trust_data_opt| {
+                if let Some(trust_data) = trust_data_opt {
+                    let seen = last_seen.unwrap_or(trust_data.joined_at);
+                    if !trust_data.flagged_for_removal && now.saturating_sub(seen) >= period {
+                        Self::flag_for_removal(trust_data, RemovalReason::Inactive);
+                        Self::deposit_event(RawEvent::ValidatorAutoExited(validator.clone()));
+                    }
+                }
+            });
+        }
+        scanned
+    }
+    
+    /// Ensure `remaining` validators would still meet the active set floor
+    fn ensure_min_active(remaining: usize) -> Result<(), Error<T>> {
+        if (remaining as u32) < T::MinActiveValidators::get() {
+            return Err(Error::<T>::TooFewValidators);
+        }
+        Ok(())
+    }
+    
+    /// Whether enough blocks have passed since the validator's last penalty to earn the recovery bonus
+    fn penalty_cooled_down(trust_data: &TrustDataOf<T>, now: T::BlockNumber) -> bool {
+        match trust_data.last_penalty_block {
+            Some(penalized_at) => now.saturating_sub(penalized_at) >= T::PenaltyCooldown::get(),
+            None => true,
+        }
+    }
+    
+    /// Count `updates` trust updates against the reporter's per-block allowance
+    fn note_reporter_updates(reporter: &T::AccountId, updates: u32) -> Result<(), Error<T>> {
+        ReporterUpdates::<T>::try_mutate(reporter, |count| {
+            if count.saturating_add(updates) > T::MaxUpdatesPerReporterPerBlock::get() {
+                return Err(Error::<T>::ReporterRateLimited);
+            }
+            *count += updates;
+            Ok(())
+        })
+    }
+    
+    /// Blend the raw score into the previous smoothed one when EMA smoothing is enabled
+    fn smoothed_score(previous: f32, raw: f32) -> f32 {
+        if !T::UseEmaSmoothing::get() {
+            return raw;
+        }
+        let alpha = permill_to_f32(T::EmaAlpha::get());
+        alpha * raw + (1.0_f32 - alpha) * previous
+    }
+    
+    /// Apply one vote's reward or penalty, with its streak, tier and removal side effects
+    fn apply_vote(validator: &T::AccountId, trust_data: &mut TrustDataOf<T>, vote_matched: bool, now: T::BlockNumber) {
+        if vote_matched {
+            let mut increase = increase_fn(trust_data.raw_score);
+            // Reward sustained good behavior since the last penalty
+            if Self::penalty_cooled_down(trust_data, now) {
+                increase *= T::RecoveryMultiplier::get();
+            }
+            trust_data.success_streak = trust_data.success_streak.saturating_add(1);
+            let interval = T::MilestoneInterval::get();
+            if interval > 0 && trust_data.success_streak % interval == 0 {
+                increase += T::MilestoneBonus::get();
+                Self::deposit_event(RawEvent::MilestoneReached(validator.clone(), trust_data.success_streak));
+            }
+            // Promote once probation has been served with an unbroken streak
+            if trust_data.tier == ValidatorTier::Probation
+                && trust_data.success_streak >= T::PromotionThreshold::get()
+            {
+                trust_data.tier = ValidatorTier::Trusted;
+                Self::deposit_event(RawEvent::TierChanged(validator.clone(), ValidatorTier::Trusted));
+            }
+            trust_data.raw_score = (trust_data.raw_score + increase).min(1.0);
+            trust_data.trust_score = Self::smoothed_score(trust_data.trust_score, trust_data.raw_score);
+            trust_data.successful_validations += 1;
+            FailureStreaks::<T>::remove(validator);
+        } else {
+            trust_data.success_streak = 0;
+            Self::penalize(trust_data, now);
+            Self::suspend_after_failure(validator, now);
+            
+            // Any failure restarts probation progress via the streak reset above
+            if trust_data.tier == ValidatorTier::Trusted
+                && trust_data.trust_score < T::ProbationThreshold::get()
+            {
+                trust_data.tier = ValidatorTier::Probation;
+                Self::deposit_event(RawEvent::TierChanged(validator.clone(), ValidatorTier::Probation));
+            }
+            
+            // Flag for removal if trust score falls below the threshold
+            if trust_data.trust_score < REMOVAL_THRESHOLD {
+                Self::flag_for_removal(trust_data, RemovalReason::LowTrust);
                 Self::deposit_event(RawEvent::ValidatorRemoved(validator.clone()));
             }
         }
     }
     
+    /// Flag a validator for removal, counting it in `FlaggedCount` unless it already was
+    fn flag_for_removal(trust_data: &mut TrustDataOf<T>, reason: RemovalReason) {
+        if !trust_data.flagged_for_removal {
+            FlaggedCount::mutate(|count| *count = count.saturating_add(1));
+        }
+        trust_data.flagged_for_removal = true;
+        trust_data.removal_reason = Some(reason);
+    }
+    
+    /// Apply one failed validation's penalty to the score and failure counters
+    fn penalize(trust_data: &mut TrustDataOf<T>, now: T::BlockNumber) {
+        let multiplier = Self::penalty_multiplier(trust_data, now);
+        let raw = trust_data.raw_score;
+        let penalized = (raw - decrease_fn(raw) * multiplier).max(0.0);
+        trust_data.raw_score = Self::floored_score(trust_data, penalized);
+        trust_data.trust_score = Self::smoothed_score(trust_data.trust_score, trust_data.raw_score);
+        trust_data.failed_validations += 1;
+        trust_data.last_penalty_block = Some(now);
+    }
+    
+    /// Stop a single penalty from taking an established validator from above `EstablishedFloor`
+    /// to below it. Only the one step is capped: from the floor down, further failures apply
+    /// their normal penalty, so sustained failures still erode the score update by update.
+    fn floored_score(trust_data: &TrustDataOf<T>, penalized: f32) -> f32 {
+        let floor = T::EstablishedFloor::get();
+        if trust_data.successful_validations > T::EstablishedValidations::get() && trust_data.raw_score > floor {
+            penalized.max(floor)
+        } else {
+            penalized
+        }
+    }
+    
+    /// Whether a task run every `period` blocks is due at `now`, and the due block to store next.
+    /// Unscheduled tasks fall due on multiples of `period`; a due task that is held by a pause
+    /// stays due, and once run is rescheduled onto the next multiple.
+    fn schedule_periodic(
+        scheduled: Option<T::BlockNumber>,
+        now: T::BlockNumber,
+        period: T::BlockNumber,
+        paused: bool,
+    ) -> (bool, T::BlockNumber) {
+        let next_boundary = (now - now % period).saturating_add(period);
+        let due_at = scheduled.unwrap_or_else(|| {
+            if !now.is_zero() && (now % period).is_zero() { now } else { next_boundary }
+        });
+        if paused || now < due_at {
+            return (false, due_at);
+        }
+        (true, next_boundary)
+    }
+    
+    /// Move every validator's `last_updated` on by `paused` blocks, never past `now`
+    fn shift_decay_clocks(paused: T::BlockNumber, now: T::BlockNumber) {
+        if paused.is_zero() {
+            return;
+        }
+        for validator in Self::validator_list() {
+            TrustScores::<T>::mutate(&validator, |trust_data_opt| {
+                if let Some(trust_data) = trust_data_opt {
+                    trust_data.last_updated = trust_data.last_updated.saturating_add(paused).min(now);
+                }
+            });
+        }
+    }
+    
+    /// Decay a validator's score for the blocks since it was last updated
+    fn apply_half_life(trust_data: &mut TrustDataOf<T>, now: T::BlockNumber) {
+        let half_life = T::HalfLife::get();
+        let elapsed = now.saturating_sub(trust_data.last_updated);
+        if half_life.is_zero() || elapsed.is_zero() {
+            return;
+        }
+        // Gaps beyond `u32::MAX` blocks saturate, which has long since decayed to zero anyway
+        let (elapsed, half_life) = (elapsed.saturated_into::<u32>(), half_life.saturated_into::<u32>());
+        let decay = |score: f32| half_life_decay(score_to_bps(score), elapsed, half_life) as f32 / 10_000_f32;
+        trust_data.trust_score = decay(trust_data.trust_score);
+        trust_data.raw_score = decay(trust_data.raw_score);
+    }
+    
+    /// Combined failure penalty multiplier from tenure leniency and below-average escalation
+    fn penalty_multiplier(trust_data: &TrustDataOf<T>, now: T::BlockNumber) -> f32 {
+        let mut multiplier = Self::tenure_multiplier(trust_data, now);
+        if T::BelowAverageEscalation::get() && trust_data.trust_score < Self::average_trust_score() {
+            multiplier *= T::BelowAverageMultiplier::get();
+        }
+        multiplier
+    }
+    
+    /// Penalty multiplier for a validator, reduced by its tenure when tenure scaling is enabled
+    fn tenure_multiplier(trust_data: &TrustDataOf<T>, now: T::BlockNumber) -> f32 {
+        if !T::TenurePenaltyScaling::get() {
+            return 1.0;
+        }
+        let period = T::TenureLeniencyPeriod::get().max(One::one());
+        let steps = (now.saturating_sub(trust_data.joined_at) / period).saturated_into::<u32>();
+        let leniency = (steps as f32 * T::TenureLeniencyStep::get()).min(T::MaxTenureLeniency::get());
+        1.0_f32 - leniency.max(0.0)
+    }
+    
+    /// Consecutive failures needed to push a validator below the removal threshold, or `None` if
+    /// failures cannot get it there within `MAX_RISK_ESTIMATE`. Failures are simulated on a copy
+    /// exactly as `apply_vote` applies them, so the established floor and the penalty multiplier
+    /// are honoured. Below 0.4 `decrease_fn` turns negative and a penalty raises the score, so the
+    /// simulation stops at the first failure that does not lower it.
+    fn failures_to_removal(trust_data: &TrustDataOf<T>, now: T::BlockNumber) -> Option<u32> {
+        if trust_data.trust_score < REMOVAL_THRESHOLD {
+            return Some(0);
+        }
+        let mut simulated = trust_data.clone();
+        for failures in 1..=MAX_RISK_ESTIMATE {
+            let before = simulated.trust_score;
+            Self::penalize(&mut simulated, now);
+            if simulated.trust_score < REMOVAL_THRESHOLD {
+                return Some(failures);
+            }
+            if simulated.trust_score >= before {
+                return None;
+            }
+        }
+        None
+    }
+    
+    /// Validators within `AtRiskMargin` of removal, with score in basis points and failures until
+    /// flagged, `None` where failures alone cannot flag them
+    pub fn validators_at_risk() -> Vec<(T::AccountId, u32, Option<u32>)> {
+        let ceiling = REMOVAL_THRESHOLD + T::AtRiskMargin::get();
+        let now = <frame_system::Module<T>>::block_number();
+        Self::validator_list()
+            .into_iter()
+            .filter_map(|validator| {
+                let data = Self::trust_scores(&validator)?;
+                if data.flagged_for_removal {
+                    return Some((validator, score_to_bps(data.trust_score), Some(0)));
+                }
+                if data.trust_score >= ceiling {
+                    return None;
+                }
+                let estimate = Self::failures_to_removal(&data, now);
+                Some((validator, score_to_bps(data.trust_score), estimate))
+            })
+            .collect()
+    }
+    
+    /// Full trust data for each requested account, in request order, `None` for unknown accounts
+    pub fn trust_data_batch(accounts: Vec<T::AccountId>) -> Vec<Option<TrustDataOf<T>>> {
+        accounts.iter().map(Self::trust_scores).collect()
+    }
+    
+    /// Vote weight of a validator: trust in basis points scaled by its tier weight, optionally
+    /// multiplied by its stake. Unknown validators carry no weight.
+    pub fn combined_weight(validator: &T::AccountId) -> u128 {
+        let trust = match Self::trust_scores(validator) {
+            Some(data) => {
+                let tier_weight = Self::tier_weights().weight_of(data.tier, data.flagged_for_removal);
+                (score_to_bps(data.trust_score) as u128).saturating_mul(tier_weight as u128)
+            }
+            None => return 0,
+        };
+        match T::VoteWeighting::get() {
+            VoteWeighting::PureTrust => trust,
+            VoteWeighting::TrustTimesStake => trust.saturating_mul(T::StakeLookup::stake_of(validator)),
+        }
+    }
+    
+    /// Sum vote weights into (yes, no) totals
+    pub fn weighted_vote_tally(votes: &[(T::AccountId, bool)]) -> (u128, u128) {
+        votes.iter().fold((0u128, 0u128), |(yes, no), (validator, vote)| {
+            let weight = Self::combined_weight(validator);
+            if *vote {
+                (yes.saturating_add(weight), no)
+            } else {
+                (yes, no.saturating_add(weight))
+            }
+        })
+    }
+    
+    /// Whether trust-weighted yes votes make up at least `quorum` of the participating weight.
+    /// Validators below `MinValidationTrust` do not participate; zero total weight never reaches quorum.
+    pub fn reaches_quorum(votes: Vec<(T::AccountId, bool)>, quorum: Permill) -> bool {
+        let min_trust = Self::min_validation_trust();
+        let participating: Vec<(T::AccountId, bool)> = votes
+            .into_iter()
+            .filter(|(validator, _)| {
+                Self::get_trust_score(validator).map_or(false, |score| score >= min_trust)
+            })
+            .collect();
+        
+        let (yes, no) = Self::weighted_vote_tally(&participating);
+        let total = yes.saturating_add(no);
+        if total == 0 {
+            return false;
+        }
+        yes.saturating_mul(1_000_000) >= (quorum.deconstruct() as u128).saturating_mul(total)
+    }
+    
     /// Get trust score for a validator
     pub fn get_trust_score(validator: &T::AccountId) -> Option<f32> {
         Self::trust_scores(validator).map(|data| data.trust_score)
     }
     
-    /// Remove validator from the system
-    fn remove_validator(validator: &T::AccountId) {
-        TrustScores::<T>::remove(validator);
+    /// Remove validator from the system, reporting its final stats for settlement
+    fn remove_validator(validator: &T::AccountId) -> Result<(), Error<T>> {
+        Self::ensure_min_active(Self::validator_list().len().saturating_sub(1))?;
+        let trust_data = Self::retire_trust_data(validator);
         ValidatorList::<T>::mutate(|list| list.retain(|v| v != validator));
-        Self::deposit_event(RawEvent::ValidatorRemoved(validator.clone()));
+        StatsDirty::put(true);
+        if let Some(data) = trust_data {
+            Self::deposit_event(RawEvent::ValidatorRemovedWithStats(
+                validator.clone(),
+                Self::event_score(data.trust_score),
+                data.successful_validations,
+                data.failed_validations,
+            ));
+        }
+        Ok(())
+    }
+    
+    /// Drop a validator's trust data, moving it into the archive when configured
+    fn retire_trust_data(validator: &T::AccountId) -> Option<TrustDataOf<T>> {
+        let trust_data = TrustScores::<T>::take(validator)?;
+        if trust_data.flagged_for_removal {
+            FlaggedCount::mutate(|count| *count = count.saturating_sub(1));
+        }
+        LastSeen::<T>::remove(validator);
+        LastExport::<T>::remove(validator);
+        RecentOutcomes::<T>::remove(validator);
+        SuspendedUntil::<T>::remove(validator);
+        FailureStreaks::<T>::remove(validator);
+        if T::ArchiveRemoved::get() {
+            let now = <frame_system::Module<T>>::block_number();
+            // Prune no sooner than the next block, which `on_initialize` still visits
+            let expires_at = now.saturating_add(T::ArchiveRetention::get().max(One::one()));
+            RemovedValidators::<T>::insert(validator, (trust_data.clone(), now));
+            ArchiveExpiry::<T>::mutate(expires_at, |due| due.push(validator.clone()));
+        } else {
+            TrustHistory::<T>::remove(validator);
+        }
+        Some(trust_data)
+    }
+    
+    /// Remove archive entries whose retention ends at `now`, returning how many were pruned
+    fn prune_archive(now: T::BlockNumber) -> u64 {
+        let retention = T::ArchiveRetention::get();
+        let mut pruned = 0u64;
+        for validator in ArchiveExpiry::<T>::take(now) {
+            // Skip entries re-archived after a later removal
+            let expired = Self::removed_validators(&validator)
+                .map(|(_, removed_at)| removed_at.saturating_add(retention.max(One::one())) <= now)
+                .unwrap_or(false);
+            if expired {
+                RemovedValidators::<T>::remove(&validator);
+                TrustHistory::<T>::remove(&validator);
+                pruned += 1;
+            }
+        }
+        pruned
+    }
+    
+    /// Share of the validator's recent outcomes that matched consensus, in basis points;
+    /// `None` before any are recorded
+    pub fn recent_success_rate(validator: &T::AccountId) -> Option<u32> {
+        let outcomes = Self::recent_outcomes(validator);
+        if outcomes.is_empty() {
+            return None;
+        }
+        Some((outcomes.iter().filter(|o| **o).count() as u64 * 10_000 / outcomes.len() as u64) as u32)
+    }
+    
+    /// Standing of a validator assembled from the individual read paths, `None` if unknown
+    pub fn validator_summary(validator: &T::AccountId) -> Option<ValidatorSummary<T::BlockNumber>> {
+        let data = Self::trust_scores(validator)?;
+        let rank = Self::all_validators_by_trust()
+            .iter()
+            .position(|(account, _)| account == validator)
+            .map(|index| index as u32 + 1);
+        Some(ValidatorSummary {
+            trust_score: score_to_bps(data.trust_score),
+            tier: data.tier,
+            rank,
+            recent_success_rate: Self::recent_success_rate(validator),
+            success_streak: data.success_streak,
+            failure_streak: Self::failure_streak(validator),
+            successful_validations: data.successful_validations,
+            failed_validations: data.failed_validations,
+            flagged_for_removal: data.flagged_for_removal,
+            removal_reason: data.removal_reason,
+            protected: Self::protected_validators(validator),
+            suspended_until: Self::suspended_until(validator),
+            last_updated: data.last_updated,
+            health_score: Self::health_score(validator),
+        })
+    }
+    
+    /// Validators sorted by their encoded account id, independent of insertion order and score
+    pub fn validators_canonical() -> Vec<T::AccountId> {
+        let mut validators = Self::validator_list();
+        validators.sort_by_cached_key(|validator| validator.encode());
+        validators
     }
     
-    /// Get validators sorted by trust score
+    /// Get validators sorted by trust score, leaving out flagged ones and those below `LeaderboardMinScore`
     pub fn get_validators_by_trust() -> Vec<(T::AccountId, u32)> {
-        let mut validators: Vec<(T::AccountId, u32)> = Self::validator_list()
+        let min_score = T::LeaderboardMinScore::get();
+        Self::ranked_validators(|data| !data.flagged_for_removal && data.trust_score >= min_score)
+    }
+    
+    /// Every validator ranked by trust, including flagged and low-score ones, for administrators
+    pub fn all_validators_by_trust() -> Vec<(T::AccountId, u32)> {
+        Self::ranked_validators(|_| true)
+    }
+    
+    /// Validators passing `include`, as (account, score in basis points) sorted by score descending.
+    /// Equal scores keep canonical order, so the ranking does not depend on insertion order.
+    fn ranked_validators(include: impl Fn(&TrustDataOf<T>) -> bool) -> Vec<(T::AccountId, u32)> {
+        let mut validators: Vec<(T::AccountId, u32)> = Self::validators_canonical()
             .into_iter()
             .filter_map(|validator| {
-                Self::get_trust_score(&validator).map(|score| (validator, score))
+                Self::trust_scores(&validator)
+                    .filter(|data| include(data))
+                    .map(|data| (validator, score_to_bps(data.trust_score)))
             })
             .collect();
         
         validators.sort_by(|a, b| b.1.cmp(&a.1)); // Sort by trust score descending
         validators
     }
+    
+    /// Recompute the average trust score and leaderboard, clearing the dirty flag
+    fn refresh_stats() {
+        let scores: Vec<f32> = Self::validator_list()
+            .iter()
+            .filter_map(Self::get_trust_score)
+            .collect();
+        if !scores.is_empty() {
+            let total: f32 = scores.iter().sum();
+            AverageTrustScore::put(total / scores.len() as f32);
+        }
+        Leaderboard::<T>::put(Self::get_validators_by_trust());
+        StatsDirty::put(false);
+    }
 
     
-}
\ No newline at end of file
+}
+
+impl<T: Config> SlashScaling<T::AccountId, BalanceOf<T>> for Module<T> {
+    fn slash_amount(who: &T::AccountId, base: BalanceOf<T>) -> BalanceOf<T> {
+        Self::slash_amount(who, base)
+    }
+}
+
+/// Transaction extension rejecting `TrustGatedCalls` from senders that fail `can_validate`,
+/// so they never enter the pool
+#[derive(Encode, Decode, Clone, Eq, PartialEq)]
+pub struct CheckValidatorTrust<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckValidatorTrust<T> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Config + Send + Sync> Default for CheckValidatorTrust<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for CheckValidatorTrust<T> {
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        write!(f, "CheckValidatorTrust")
+    }
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckValidatorTrust<T> {
+    const IDENTIFIER: &'static str = "CheckValidatorTrust";
+    type AccountId = T::AccountId;
+    type Call = <T as frame_system::Config>::Call;
+    type AdditionalSigned = ();
+    type Pre = ();
+    
+    fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+        Ok(())
+    }
+    
+    fn validate(
+        &self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        _info: &DispatchInfoOf<Self::Call>,
+        _len: usize,
+    ) -> TransactionValidity {
+        if T::TrustGatedCalls::filter(call) && !Module::<T>::can_validate(who) {
+            return InvalidTransaction::Custom(LOW_TRUST_TRANSACTION).into();
+        }
+        Ok(ValidTransaction::default())
+    }
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait TrustScoreApi<AccountId, BlockNumber> where AccountId: Codec, BlockNumber: Codec {
+        /// Validators close to removal: (account, score in basis points, failures until flagged,
+        /// `None` if failures alone cannot flag it)
+        fn validators_at_risk() -> Vec<(AccountId, u32, Option<u32>)>;
+        
+        /// Full trust data per requested account, preserving order
+        fn trust_data_batch(accounts: Vec<AccountId>) -> Vec<Option<NodeTrustData<AccountId, BlockNumber>>>;
+        
+        /// Trust changes (account, block, score in basis points) within a block range, one
+        /// `AUDIT_PAGE_SIZE` page at a time
+        fn trust_changes_between(
+            from_block: BlockNumber,
+            to_block: BlockNumber,
+            page: u32,
+        ) -> Vec<(AccountId, BlockNumber, u32)>;
+        
+        /// Mean and median trust score in basis points; the median is `None` with no validators
+        fn trust_score_summary() -> (u32, Option<u32>);
+        
+        /// Combined trust, recent success rate and recency in basis points
+        fn health_score(validator: AccountId) -> Option<u32>;
+        
+        /// Public leaderboard: unflagged validators at or above `LeaderboardMinScore`
+        fn get_validators_by_trust() -> Vec<(AccountId, u32)>;
+        
+        /// Every validator ranked by trust, for administrators
+        fn all_validators_by_trust() -> Vec<(AccountId, u32)>;
+        
+        /// Validators in canonical (encoded account id) order
+        fn validators_canonical() -> Vec<AccountId>;
+        
+        /// Score, tier, rank, streaks, flags and health of one validator in a single call
+        fn validator_summary(validator: AccountId) -> Option<ValidatorSummary<BlockNumber>>;
+    }
+}
@@ -0,0 +1,338 @@
+use crate as trust_score;
+use crate::{FullSetPolicy, ReputationBootstrap, StakeLookup, VoteWeighting, XcmSender};
+use frame_support::{
+    parameter_types,
+    traits::{Filter, OnFinalize, OnIdle, OnInitialize},
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    Permill,
+};
+use std::cell::RefCell;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        TrustScore: trust_score::{Pallet, Call, Config, Storage, Event<T>},
+    }
+);
+
+/// Pays validator rewards in tests
+pub const REWARD_SOURCE: u64 = 100;
+
+/// Starts above neutral through the bootstrap oracle
+pub const BOOTSTRAPPED: u64 = 42;
+
+/// Reported to the oracle with an out-of-range score
+pub const OVERSCORED: u64 = 43;
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const ExistentialDeposit: u64 = 1;
+
+    pub const MaxTrustScore: f32 = 1.0;
+    pub const MinTrustScore: f32 = 0.0;
+    pub const SuccessReward: f32 = 0.01;
+    pub const FailurePenalty: f32 = 0.01;
+    pub const PenaltyCooldown: u64 = 10;
+    pub const RecoveryMultiplier: f32 = 2.0;
+    pub const EmaAlpha: Permill = Permill::from_percent(50);
+    pub const AtRiskMargin: f32 = 0.2;
+    pub const TenureLeniencyPeriod: u64 = 100;
+    pub const TenureLeniencyStep: f32 = 0.1;
+    pub const MaxTenureLeniency: f32 = 0.5;
+    pub const InitialTrustScore: f32 = 0.5;
+    pub const CarryoverFactor: Permill = Permill::from_percent(50);
+    pub const MilestoneBonus: f32 = 0.05;
+    pub const BelowAverageMultiplier: f32 = 2.0;
+    pub const ProbationThreshold: f32 = 0.3;
+    pub const PromotionThreshold: u32 = 3;
+    pub const MaxHistoryLength: u32 = 5;
+    pub const DisputeBond: u64 = 50;
+    pub const HealthWindow: u32 = 4;
+    pub const HealthRecencyPeriod: u64 = 100;
+    pub const LeaderboardMinScore: f32 = 0.3;
+    pub const MaxSlashMultiple: u32 = 4;
+    pub const EstablishedFloor: f32 = 0.3;
+    pub const SuspensionLength: u64 = 5;
+    pub const MaxSuspensionLength: u64 = 12;
+    pub const RewardSource: u64 = REWARD_SOURCE;
+    pub const MaxBatchSize: u32 = 20;
+    pub const ExportCooldown: u64 = 10;
+
+    // Switched per test through `ExtBuilder`
+    pub storage MaxUpdatesPerReporterPerBlock: u32 = 100;
+    pub storage UseEmaSmoothing: bool = false;
+    pub storage ArchiveRemoved: bool = false;
+    pub storage ArchiveRetention: u64 = 10;
+    pub storage MinActiveValidators: u32 = 0;
+    pub storage TenurePenaltyScaling: bool = false;
+    pub storage MaxValidators: u32 = 100;
+    pub storage OnFull: FullSetPolicy = FullSetPolicy::Reject;
+    pub storage SeasonLength: u64 = 0;
+    pub storage MilestoneInterval: u32 = 0;
+    pub storage Weighting: VoteWeighting = VoteWeighting::PureTrust;
+    pub storage BelowAverageEscalation: bool = false;
+    pub storage HalfLife: u64 = 0;
+    pub storage EventScorePrecision: u32 = 10_000;
+    pub storage AutoExitPeriod: u64 = 0;
+    pub storage HealthTrustWeight: u32 = 1;
+    pub storage HealthSuccessWeight: u32 = 1;
+    pub storage HealthRecencyWeight: u32 = 1;
+    pub storage EmitScoreDelta: bool = false;
+    pub storage DigestTopN: u32 = 0;
+    pub storage EstablishedValidations: u32 = 1_000;
+    pub storage OnboardingDelay: u64 = 0;
+    pub storage SuspendOnFailure: bool = false;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = ();
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type Origin = Origin;
+    type Call = Call;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = Event;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type DustRemoval = ();
+    type Event = Event;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+
+/// Stake equal to the account id, so stake-weighted tallies differ from pure trust
+pub struct MockStake;
+impl StakeLookup<u64> for MockStake {
+    fn stake_of(who: &u64) -> u128 {
+        *who as u128
+    }
+}
+
+/// Knows `BOOTSTRAPPED` (0.8) and `OVERSCORED` (out of range); nobody else
+pub struct MockBootstrap;
+impl ReputationBootstrap<u64> for MockBootstrap {
+    fn starting_score(who: &u64) -> Option<f32> {
+        match *who {
+            BOOTSTRAPPED => Some(0.8),
+            OVERSCORED => Some(5.0),
+            _ => None,
+        }
+    }
+}
+
+thread_local! {
+    /// Payloads handed to `MockXcm`, with their destination
+    pub static SENT_EXPORTS: RefCell<Vec<(u32, Vec<u8>)>> = RefCell::new(Vec::new());
+}
+
+/// Records every payload; destination 0 is unreachable
+pub struct MockXcm;
+impl XcmSender for MockXcm {
+    type Destination = u32;
+
+    fn send(dest: u32, payload: Vec<u8>) -> Result<(), ()> {
+        if dest == 0 {
+            return Err(());
+        }
+        SENT_EXPORTS.with(|sent| sent.borrow_mut().push((dest, payload)));
+        Ok(())
+    }
+}
+
+/// Gates heartbeats behind `can_validate`
+pub struct GatedCalls;
+impl Filter<Call> for GatedCalls {
+    fn filter(call: &Call) -> bool {
+        matches!(call, Call::TrustScore(trust_score::Call::heartbeat()))
+    }
+}
+
+impl trust_score::Config for Test {
+    type Event = Event;
+    type AdminOrigin = EnsureRoot<u64>;
+    type MaxTrustScore = MaxTrustScore;
+    type MinTrustScore = MinTrustScore;
+    type SuccessReward = SuccessReward;
+    type FailurePenalty = FailurePenalty;
+    type PenaltyCooldown = PenaltyCooldown;
+    type RecoveryMultiplier = RecoveryMultiplier;
+    type MaxUpdatesPerReporterPerBlock = MaxUpdatesPerReporterPerBlock;
+    type UseEmaSmoothing = UseEmaSmoothing;
+    type EmaAlpha = EmaAlpha;
+    type ArchiveRemoved = ArchiveRemoved;
+    type ArchiveRetention = ArchiveRetention;
+    type MinActiveValidators = MinActiveValidators;
+    type AtRiskMargin = AtRiskMargin;
+    type TenurePenaltyScaling = TenurePenaltyScaling;
+    type TenureLeniencyPeriod = TenureLeniencyPeriod;
+    type TenureLeniencyStep = TenureLeniencyStep;
+    type MaxTenureLeniency = MaxTenureLeniency;
+    type MaxValidators = MaxValidators;
+    type OnFull = OnFull;
+    type InitialTrustScore = InitialTrustScore;
+    type SeasonLength = SeasonLength;
+    type CarryoverFactor = CarryoverFactor;
+    type MilestoneInterval = MilestoneInterval;
+    type MilestoneBonus = MilestoneBonus;
+    type StakeLookup = MockStake;
+    type VoteWeighting = Weighting;
+    type ReputationBootstrap = MockBootstrap;
+    type BelowAverageEscalation = BelowAverageEscalation;
+    type BelowAverageMultiplier = BelowAverageMultiplier;
+    type HalfLife = HalfLife;
+    type ProbationThreshold = ProbationThreshold;
+    type PromotionThreshold = PromotionThreshold;
+    type EventScorePrecision = EventScorePrecision;
+    type MaxHistoryLength = MaxHistoryLength;
+    type AutoExitPeriod = AutoExitPeriod;
+    type Currency = Balances;
+    type DisputeBond = DisputeBond;
+    type HealthWindow = HealthWindow;
+    type HealthRecencyPeriod = HealthRecencyPeriod;
+    type HealthTrustWeight = HealthTrustWeight;
+    type HealthSuccessWeight = HealthSuccessWeight;
+    type HealthRecencyWeight = HealthRecencyWeight;
+    type EmitScoreDelta = EmitScoreDelta;
+    type LeaderboardMinScore = LeaderboardMinScore;
+    type DigestTopN = DigestTopN;
+    type MaxSlashMultiple = MaxSlashMultiple;
+    type EstablishedValidations = EstablishedValidations;
+    type EstablishedFloor = EstablishedFloor;
+    type OnboardingDelay = OnboardingDelay;
+    type TrustGatedCalls = GatedCalls;
+    type XcmSender = MockXcm;
+    type ExportCooldown = ExportCooldown;
+    type SuspendOnFailure = SuspendOnFailure;
+    type SuspensionLength = SuspensionLength;
+    type MaxSuspensionLength = MaxSuspensionLength;
+    type RewardSource = RewardSource;
+    type MaxBatchSize = MaxBatchSize;
+}
+
+/// Test externalities with funded accounts 1..=10 and the reward source, at block 1
+pub struct ExtBuilder {
+    setup: Vec<Box<dyn FnOnce()>>,
+    validators: Vec<u64>,
+}
+
+impl Default for ExtBuilder {
+    fn default() -> Self {
+        ExtBuilder { setup: Vec::new(), validators: Vec::new() }
+    }
+}
+
+impl ExtBuilder {
+    /// Apply a config switch before genesis validators are added
+    pub fn with(mut self, setup: impl FnOnce() + 'static) -> Self {
+        self.setup.push(Box::new(setup));
+        self
+    }
+
+    /// Validators initialized at block 1
+    pub fn validators(mut self, validators: Vec<u64>) -> Self {
+        self.validators = validators;
+        self
+    }
+
+    pub fn build(self) -> sp_io::TestExternalities {
+        let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+        let mut balances: Vec<(u64, u64)> = (1..=10).map(|who| (who, 1_000)).collect();
+        balances.push((REWARD_SOURCE, 1_000_000));
+        pallet_balances::GenesisConfig::<Test> { balances }
+            .assimilate_storage(&mut storage)
+            .unwrap();
+        trust_score::GenesisConfig::default()
+            .assimilate_storage::<Test>(&mut storage)
+            .unwrap();
+
+        let mut ext = sp_io::TestExternalities::new(storage);
+        ext.execute_with(|| {
+            SENT_EXPORTS.with(|sent| sent.borrow_mut().clear());
+            System::set_block_number(1);
+            for setup in self.setup {
+                setup();
+            }
+            for validator in self.validators {
+                TrustScore::initialize_validator(Origin::root(), validator).unwrap();
+            }
+            System::reset_events();
+        });
+        ext
+    }
+}
+
+/// Finalize the current block and initialize each following one up to `n`, with idle time
+pub fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        let now = System::block_number();
+        TrustScore::on_idle(now, u64::MAX);
+        TrustScore::on_finalize(now);
+        System::set_block_number(now + 1);
+        TrustScore::on_initialize(now + 1);
+    }
+}
+
+/// Pallet events emitted so far, oldest first
+pub fn trust_events() -> Vec<trust_score::Event<Test>> {
+    System::events()
+        .into_iter()
+        .filter_map(|record| match record.event {
+            Event::TrustScore(inner) => Some(inner),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Current stored score of a validator
+pub fn score(who: u64) -> f32 {
+    TrustScore::get_trust_score(&who).expect("validator exists")
+}
+
+/// Overwrite a validator's stored score, smoothed and raw alike
+pub fn set_score(who: u64, trust_score: f32) {
+    trust_score::TrustScores::<Test>::mutate(who, |data| {
+        let data = data.as_mut().expect("validator exists");
+        data.trust_score = trust_score;
+        data.raw_score = trust_score;
+    });
+}
+
+/// Report one vote for `who` from reporter 9 in no particular round
+pub fn report(who: u64, matched: bool) {
+    TrustScore::update_trust_score(Origin::signed(9), who, matched, None).unwrap();
+}
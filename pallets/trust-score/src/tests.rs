@@ -0,0 +1,1473 @@
+use crate::mock::*;
+use crate::*;
+use frame_support::{
+    assert_noop, assert_ok, assert_storage_noop,
+    traits::{OnFinalize, OnIdle, OnRuntimeUpgrade},
+    weights::{DispatchInfo, Pays},
+    StorageHasher,
+};
+use sp_runtime::traits::BadOrigin;
+
+fn approx(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-5
+}
+
+fn bps(trust_score: f32) -> u32 {
+    (trust_score * 10_000_f32) as u32
+}
+
+fn data(who: u64) -> TrustDataOf<Test> {
+    TrustScore::trust_scores(who).expect("validator exists")
+}
+
+fn report_as(reporter: u64, who: u64, matched: bool) -> DispatchResult {
+    TrustScore::update_trust_score(Origin::signed(reporter), who, matched, None).map_err(Into::into)
+}
+
+fn batch(who: u64, matched: u32, total: u32) -> DispatchResult {
+    TrustScore::update_trust_score_batch(Origin::signed(9), who, matched, total).map_err(Into::into)
+}
+
+/// Whether `value` survives an encode/decode round trip byte-for-byte, so nodes
+/// exchanging it (e.g. `NodeTrustData`, whose score is an `f32`) agree on its encoding
+fn encoding_is_stable<V: Encode + Decode>(value: &V) -> bool {
+    let encoded = value.encode();
+    V::decode(&mut &encoded[..])
+        .map(|decoded| decoded.encode() == encoded)
+        .unwrap_or(false)
+}
+
+/// Drive a validator to removal with one failure just above the curve's pole at 0.4
+fn flag(who: u64) {
+    set_score(who, 0.4005);
+    report(who, false);
+    assert!(data(who).flagged_for_removal);
+}
+
+fn set_flagged(who: u64) {
+    TrustScores::<Test>::mutate(who, |data| TrustScore::flag_for_removal(data.as_mut().unwrap(), RemovalReason::LowTrust));
+}
+
+fn count_events(matches: impl Fn(&crate::Event<Test>) -> bool) -> usize {
+    trust_events().iter().filter(|event| matches(event)).count()
+}
+
+/// Write a `Releases::V1` entry for `who` straight into storage
+fn put_legacy(who: u64, last_updated: u32, flagged: bool) {
+    let old = OldNodeTrustData {
+        validator: who,
+        trust_score: 0.6,
+        successful_validations: 7,
+        failed_validations: 2,
+        last_updated,
+        flagged_for_removal: flagged,
+    };
+    frame_support::storage::unhashed::put(&TrustScores::<Test>::hashed_key_for(&who), &old);
+}
+
+#[test]
+fn recovery_bonus_waits_out_the_penalty_cooldown() {
+    ExtBuilder::default().validators(vec![1, 2]).build().execute_with(|| {
+        report(2, false);
+        set_score(2, 0.5);
+
+        report(1, true);
+        report(2, true);
+        let clean_gain = score(1) - 0.5;
+        let penalized_gain = score(2) - 0.5;
+        assert!(approx(clean_gain, penalized_gain * RecoveryMultiplier::get()));
+
+        run_to_block(1 + PenaltyCooldown::get());
+        set_score(2, 0.5);
+        report(2, true);
+        assert!(approx(score(2) - 0.5, clean_gain));
+    });
+}
+
+#[test]
+fn risk_estimates_match_the_failures_that_flag_a_validator() {
+    ExtBuilder::default()
+        .with(|| EstablishedValidations::set(&5))
+        .validators(vec![1, 2])
+        .build()
+        .execute_with(|| {
+            // One failure just above 0.4 takes a validator out, unless the established floor holds
+            // it at 0.3, where further failures only raise the score
+            set_score(1, 0.4005);
+            set_score(2, 0.4005);
+            TrustScores::<Test>::mutate(2, |data| data.as_mut().unwrap().successful_validations = 10);
+            assert_eq!(TrustScore::failures_to_removal(&data(1), 1), Some(1));
+            assert_eq!(TrustScore::failures_to_removal(&data(2), 1), None);
+
+            report(1, false);
+            report(2, false);
+            assert!(data(1).flagged_for_removal);
+            assert!(!data(2).flagged_for_removal);
+            assert_eq!(score(2), EstablishedFloor::get());
+        });
+}
+
+#[test]
+fn at_risk_lists_flagged_and_low_validators_only() {
+    ExtBuilder::default().validators(vec![1, 2, 3]).build().execute_with(|| {
+        set_score(1, 0.15);
+        set_score(2, 0.5);
+        set_flagged(3);
+
+        let at_risk = TrustScore::validators_at_risk();
+        let estimate = |who: u64| at_risk.iter().find(|(v, ..)| *v == who).map(|(_, _, failures)| *failures);
+        // Below 0.4 a failure raises the score, so failures alone never flag validator 1
+        assert_eq!(estimate(1), Some(None));
+        assert_eq!(estimate(2), None);
+        assert_eq!(estimate(3), Some(Some(0)));
+    });
+}
+
+#[test]
+fn risk_estimates_count_the_failures_from_each_distance() {
+    ExtBuilder::default().validators(vec![1]).build().execute_with(|| {
+        let estimate = |trust_score: f32| {
+            set_score(1, trust_score);
+            TrustScore::failures_to_removal(&data(1), 1)
+        };
+        assert_eq!(estimate(0.05), Some(0));
+        assert_eq!(estimate(0.4005), Some(1));
+        assert_eq!(estimate(0.4208), Some(2));
+        assert_eq!(estimate(0.4337), Some(3));
+        assert_eq!(estimate(0.4438), Some(4));
+        // Landing just below 0.4 bounces the score back up
+        assert_eq!(estimate(0.41), None);
+        assert_eq!(estimate(0.15), None);
+        // Further than `MAX_RISK_ESTIMATE` failures is out of reach
+        assert_eq!(estimate(0.9), None);
+
+        // The estimate matches what reported failures actually do
+        set_score(1, 0.4337);
+        for _ in 0..3 {
+            assert!(!data(1).flagged_for_removal);
+            report(1, false);
+        }
+        assert!(data(1).flagged_for_removal);
+    });
+}
+
+#[test]
+fn legacy_entries_are_translated_into_the_current_layout() {
+    ExtBuilder::default().build().execute_with(|| {
+        run_to_block(50);
+        put_legacy(1, 20, false);
+        put_legacy(2, 0, true);
+        put_legacy(3, 80, false);
+        StorageVersion::put(Releases::V1);
+
+        TrustScore::on_runtime_upgrade();
+
+        assert_eq!(TrustScore::storage_version(), Releases::V3);
+        let one = data(1);
+        assert_eq!((one.trust_score, one.successful_validations, one.failed_validations), (0.6, 7, 2));
+        assert_eq!((one.last_updated, one.joined_at, one.success_streak), (20, 20, 0));
+        assert_eq!(one.removal_reason, None);
+        let two = data(2);
+        assert_eq!(two.last_updated, 50);
+        assert!(two.flagged_for_removal);
+        assert_eq!(two.removal_reason, Some(RemovalReason::LowTrust));
+        // A timestamp from the future restarts the decay clock now
+        assert_eq!(data(3).last_updated, 50);
+    });
+}
+
+#[test]
+fn v2_block_fields_are_widened_past_u32() {
+    ExtBuilder::default().build().execute_with(|| {
+        let old = V2NodeTrustData {
+            validator: 1u64,
+            trust_score: 0.05,
+            successful_validations: 3,
+            failed_validations: 9,
+            success_streak: 0,
+            last_updated: 40,
+            joined_at: 10,
+            last_penalty_block: Some(40),
+            flagged_for_removal: true,
+            tier: ValidatorTier::Probation,
+        };
+        frame_support::storage::unhashed::put(&TrustScores::<Test>::hashed_key_for(&1u64), &old);
+        frame_support::storage::migration::put_storage_value(
+            b"TrustScore",
+            b"ArchiveExpiry",
+            &Twox64Concat::hash(&60u32.encode()),
+            vec![7u64],
+        );
+        frame_support::storage::unhashed::put(&LastSeen::<Test>::hashed_key_for(&1u64), &40u32);
+        StorageVersion::put(Releases::V2);
+
+        TrustScore::on_runtime_upgrade();
+
+        assert_eq!(TrustScore::storage_version(), Releases::V3);
+        let one = data(1);
+        assert_eq!((one.last_updated, one.joined_at, one.last_penalty_block), (40, 10, Some(40)));
+        assert_eq!(one.removal_reason, Some(RemovalReason::LowTrust));
+        assert_eq!(TrustScore::last_seen(1), Some(40));
+        assert_eq!(TrustScore::archive_expiry(60), vec![7]);
+
+        // Blocks past u32::MAX are stored as they are rather than saturated
+        let late = u32::MAX as u64 + 10;
+        TrustScore::initialize_validator(Origin::root(), 2).unwrap();
+        System::set_block_number(late);
+        report(2, true);
+        assert_eq!(data(2).last_updated, late);
+        assert_eq!(TrustScore::last_seen(2), Some(late));
+        assert_eq!(TrustScore::trust_history(2).last().map(|(block, _)| *block), Some(late));
+    });
+}
+
+#[test]
+fn a_new_chain_starts_on_the_current_layout() {
+    ExtBuilder::default().validators(vec![1, 2]).build().execute_with(|| {
+        assert_eq!(TrustScore::storage_version(), Releases::V3);
+        report(1, false);
+        let raw = |who: u64| frame_support::storage::unhashed::get_raw(&TrustScores::<Test>::hashed_key_for(&who));
+        let before = (raw(1), raw(2));
+
+        TrustScore::on_runtime_upgrade();
+
+        assert_eq!((raw(1), raw(2)), before);
+        assert_eq!(TrustScore::storage_version(), Releases::V3);
+    });
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn try_runtime_checks_pass_around_the_legacy_migration() {
+    ExtBuilder::default().build().execute_with(|| {
+        run_to_block(50);
+        put_legacy(1, 20, false);
+        put_legacy(2, 0, true);
+        put_legacy(3, 80, false);
+        StorageVersion::put(Releases::V1);
+
+        assert_ok!(TrustScore::pre_upgrade());
+        TrustScore::on_runtime_upgrade();
+        assert_ok!(TrustScore::post_upgrade());
+        assert_eq!(TrustScores::<Test>::iter_keys().count(), 3);
+    });
+}
+
+#[test]
+fn smoothed_score_is_an_ema_of_the_raw_score() {
+    let sequence = [true, false, true, true, false, false, true, false];
+    let run = |smoothed: bool| {
+        let mut scores = Vec::new();
+        ExtBuilder::default()
+            .with(move || UseEmaSmoothing::set(&smoothed))
+            .validators(vec![1])
+            .build()
+            .execute_with(|| {
+                for matched in sequence.iter() {
+                    report(1, *matched);
+                    scores.push((data(1).raw_score, score(1)));
+                }
+            });
+        scores
+    };
+    let unsmoothed = run(false);
+    let smoothed = run(true);
+
+    let alpha = 0.5;
+    let mut previous = 0.5;
+    for ((raw, published), (expected_raw, _)) in smoothed.iter().zip(unsmoothed.iter()) {
+        // The raw score follows votes exactly as it would without smoothing
+        assert!(approx(*raw, *expected_raw));
+        assert!(approx(*published, alpha * raw + (1.0 - alpha) * previous));
+        previous = *published;
+    }
+    assert!(unsmoothed.iter().all(|(raw, published)| raw == published));
+}
+
+#[test]
+fn empty_cleanup_is_a_free_single_read() {
+    ExtBuilder::default().validators(vec![1, 2, 3]).build().execute_with(|| {
+        assert_storage_noop!(TrustScore::cleanup_validators(Origin::signed(9)).unwrap());
+        let info = TrustScore::cleanup_validators(Origin::signed(9)).unwrap();
+        assert_eq!(info.pays_fee, Pays::No);
+        assert_eq!(info.actual_weight, Some(<Test as frame_system::Config>::DbWeight::get().reads(1)));
+        assert!(trust_events().is_empty());
+    });
+}
+
+#[test]
+fn flagged_count_tracks_flags_and_removals() {
+    ExtBuilder::default().validators(vec![1, 2, 3]).build().execute_with(|| {
+        set_score(1, 0.4005);
+        report(1, false);
+        report(1, false);
+        set_flagged(2);
+        set_flagged(2);
+        assert_eq!(TrustScore::flagged_count(), 2);
+
+        let info = TrustScore::cleanup_validators(Origin::signed(9)).unwrap();
+        assert_eq!(info.pays_fee, Pays::Yes);
+        assert_eq!(TrustScore::validator_list(), vec![3]);
+        assert_eq!(TrustScore::flagged_count(), 0);
+    });
+}
+
+#[test]
+fn boundaries_passed_while_paused_are_caught_up_on_resume() {
+    ExtBuilder::default()
+        .with(|| {
+            SeasonLength::set(&10);
+            AutoExitPeriod::set(&10);
+        })
+        .validators(vec![1])
+        .build()
+        .execute_with(|| {
+            set_score(1, 0.9);
+            assert_ok!(TrustScore::set_trust_adjustments_paused(Origin::root(), true));
+            run_to_block(25);
+            assert_eq!(TrustScore::current_season(), 0);
+            assert!(!data(1).flagged_for_removal);
+
+            assert_ok!(TrustScore::set_trust_adjustments_paused(Origin::root(), false));
+            run_to_block(26);
+            // The missed boundaries run once, then the schedule is back on multiples of the period
+            assert_eq!(TrustScore::current_season(), 1);
+            assert_eq!(data(1).removal_reason, Some(RemovalReason::Inactive));
+            assert_eq!(TrustScore::next_season_reset(), Some(30));
+            assert_eq!(TrustScore::next_auto_exit(), Some(30));
+
+            run_to_block(30);
+            assert_eq!(TrustScore::current_season(), 2);
+        });
+}
+
+#[test]
+fn trust_data_encoding_is_stable_at_edge_scores() {
+    ExtBuilder::default().validators(vec![1]).build().execute_with(|| {
+        for edge in [0.0, REMOVAL_THRESHOLD, 1.0, f32::MIN_POSITIVE, -0.0].iter() {
+            set_score(1, *edge);
+            assert!(encoding_is_stable(&data(1)));
+        }
+        let mut extreme = data(1);
+        extreme.successful_validations = u32::MAX;
+        extreme.last_updated = u64::MAX;
+        extreme.last_penalty_block = Some(u64::MAX);
+        extreme.removal_reason = Some(RemovalReason::Inactive);
+        assert!(encoding_is_stable(&extreme));
+    });
+}
+
+#[test]
+fn score_math_encodes_to_the_same_bytes_on_every_run() {
+    let run = || {
+        ExtBuilder::default().validators(vec![1]).build().execute_with(|| {
+            for matched in [true, true, false, true, false, false, true].iter() {
+                report(1, *matched);
+            }
+            data(1)
+        })
+    };
+    let first = run();
+    assert!(encoding_is_stable(&first));
+    // The score is carried as its raw little-endian bits, right after the account id
+    assert_eq!(first.encode()[8..12], first.trust_score.to_le_bytes());
+    assert_eq!(first.encode(), run().encode());
+}
+
+#[test]
+fn removed_validators_are_archived_then_pruned() {
+    ExtBuilder::default()
+        .with(|| ArchiveRemoved::set(&true))
+        .validators(vec![1, 2])
+        .build()
+        .execute_with(|| {
+            flag(1);
+            let final_data = data(1);
+            assert_ok!(TrustScore::cleanup_validators(Origin::signed(9)));
+
+            assert!(TrustScore::trust_scores(1).is_none());
+            assert_eq!(TrustScore::removed_validators(1), Some((final_data, 1)));
+            assert!(!TrustScore::trust_history(1).is_empty());
+
+            run_to_block(ArchiveRetention::get());
+            assert!(TrustScore::removed_validators(1).is_some());
+            run_to_block(1 + ArchiveRetention::get());
+            assert!(TrustScore::removed_validators(1).is_none());
+            assert!(TrustScore::trust_history(1).is_empty());
+        });
+}
+
+#[test]
+fn zero_retention_prunes_on_the_next_block() {
+    ExtBuilder::default()
+        .with(|| {
+            ArchiveRemoved::set(&true);
+            ArchiveRetention::set(&0);
+        })
+        .validators(vec![1, 2])
+        .build()
+        .execute_with(|| {
+            flag(1);
+            assert_ok!(TrustScore::cleanup_validators(Origin::signed(9)));
+            assert!(TrustScore::removed_validators(1).is_some());
+
+            run_to_block(2);
+            assert!(TrustScore::removed_validators(1).is_none());
+            assert!(TrustScore::archive_expiry(2).is_empty());
+        });
+}
+
+#[test]
+fn reporter_is_throttled_within_a_block_and_reset_on_the_next() {
+    ExtBuilder::default()
+        .with(|| MaxUpdatesPerReporterPerBlock::set(&3))
+        .validators(vec![1])
+        .build()
+        .execute_with(|| {
+            for _ in 0..3 {
+                assert_ok!(report_as(9, 1, true));
+            }
+            assert_noop!(report_as(9, 1, true), Error::<Test>::ReporterRateLimited);
+            // Other reporters have their own allowance
+            assert_ok!(report_as(8, 1, true));
+
+            run_to_block(2);
+            assert_ok!(report_as(9, 1, true));
+        });
+}
+
+#[test]
+fn removed_validators_are_deleted_when_archiving_is_off() {
+    ExtBuilder::default().validators(vec![1, 2]).build().execute_with(|| {
+        flag(1);
+        assert_ok!(TrustScore::cleanup_validators(Origin::signed(9)));
+
+        assert!(TrustScore::trust_scores(1).is_none());
+        assert!(TrustScore::removed_validators(1).is_none());
+        assert!(TrustScore::trust_history(1).is_empty());
+        assert_eq!(TrustScore::validator_list(), vec![2]);
+    });
+}
+
+#[test]
+fn cleanup_is_refused_at_the_active_floor() {
+    ExtBuilder::default()
+        .with(|| MinActiveValidators::set(&2))
+        .validators(vec![1, 2, 3])
+        .build()
+        .execute_with(|| {
+            flag(1);
+            flag(2);
+            assert_noop!(TrustScore::cleanup_validators(Origin::signed(9)), Error::<Test>::TooFewValidators);
+        });
+}
+
+#[test]
+fn cleanup_proceeds_above_the_active_floor() {
+    ExtBuilder::default()
+        .with(|| MinActiveValidators::set(&2))
+        .validators(vec![1, 2, 3])
+        .build()
+        .execute_with(|| {
+            flag(1);
+            assert_ok!(TrustScore::cleanup_validators(Origin::signed(9)));
+            assert_eq!(TrustScore::validator_list(), vec![2, 3]);
+        });
+}
+
+#[test]
+fn removal_reports_final_stats_exactly_once() {
+    ExtBuilder::default().validators(vec![1, 2]).build().execute_with(|| {
+        report(1, true);
+        flag(1);
+        let final_data = data(1);
+        assert_ok!(TrustScore::cleanup_validators(Origin::signed(9)));
+
+        let expected = RawEvent::ValidatorRemovedWithStats(
+            1,
+            bps(final_data.trust_score),
+            final_data.successful_validations,
+            final_data.failed_validations,
+        );
+        assert_eq!((final_data.successful_validations, final_data.failed_validations), (1, 1));
+        assert_eq!(count_events(|event| *event == expected), 1);
+    });
+}
+
+#[test]
+fn veterans_are_penalized_less_than_newcomers() {
+    ExtBuilder::default()
+        .with(|| TenurePenaltyScaling::set(&true))
+        .validators(vec![1])
+        .build()
+        .execute_with(|| {
+            run_to_block(1 + 3 * TenureLeniencyPeriod::get());
+            assert_ok!(TrustScore::initialize_validator(Origin::root(), 2));
+            set_score(1, 0.5);
+
+            report(1, false);
+            report(2, false);
+            let veteran_loss = 0.5 - score(1);
+            let newcomer_loss = 0.5 - score(2);
+            assert!(approx(veteran_loss, newcomer_loss * (1.0 - 3.0 * TenureLeniencyStep::get())));
+        });
+}
+
+#[test]
+fn average_converges_after_idle_processing() {
+    ExtBuilder::default().validators(vec![1, 2, 3]).build().execute_with(|| {
+        for _ in 0..5 {
+            report(1, true);
+            report(2, false);
+        }
+        // Updates leave the expensive aggregate for idle time
+        assert!(TrustScore::stats_dirty());
+        assert_eq!(TrustScore::average_trust_score(), 0.5);
+
+        TrustScore::on_idle(1, u64::MAX);
+        let expected = (score(1) + score(2) + score(3)) / 3.0;
+        assert!(approx(TrustScore::average_trust_score(), expected));
+        assert!(!TrustScore::stats_dirty());
+        assert_eq!(TrustScore::leaderboard()[0].0, 1);
+    });
+}
+
+#[test]
+fn faulted_rounds_waive_penalties() {
+    ExtBuilder::default().validators(vec![1]).build().execute_with(|| {
+        assert_noop!(TrustScore::report_network_fault(Origin::signed(1), 7), BadOrigin);
+        assert_ok!(TrustScore::report_network_fault(Origin::root(), 7));
+
+        assert_ok!(TrustScore::update_trust_score(Origin::signed(9), 1, false, Some(7)));
+        assert_eq!(score(1), 0.5);
+        assert_eq!(trust_events().last(), Some(&RawEvent::PenaltyWaived(1, 7)));
+
+        assert_ok!(TrustScore::update_trust_score(Origin::signed(9), 1, false, Some(8)));
+        assert!(score(1) < 0.5);
+    });
+}
+
+#[test]
+fn initialization_is_admin_only_and_rejects_duplicates() {
+    ExtBuilder::default().validators(vec![1]).build().execute_with(|| {
+        assert_noop!(TrustScore::initialize_validator(Origin::signed(2), 2), BadOrigin);
+        assert_noop!(TrustScore::initialize_validator(Origin::root(), 1), Error::<Test>::ValidatorAlreadyExists);
+    });
+}
+
+#[test]
+fn full_set_rejects_new_validators() {
+    ExtBuilder::default()
+        .with(|| MaxValidators::set(&2))
+        .validators(vec![1, 2])
+        .build()
+        .execute_with(|| {
+            assert_noop!(TrustScore::initialize_validator(Origin::root(), 3), Error::<Test>::TooManyValidators);
+        });
+}
+
+#[test]
+fn full_set_evicts_the_lowest_unprotected_validator() {
+    ExtBuilder::default()
+        .with(|| {
+            MaxValidators::set(&2);
+            OnFull::set(&FullSetPolicy::EvictLowest);
+        })
+        .validators(vec![1, 2])
+        .build()
+        .execute_with(|| {
+            set_score(1, 0.2);
+            assert_ok!(TrustScore::set_validator_protection(Origin::root(), 1, true));
+
+            assert_ok!(TrustScore::initialize_validator(Origin::root(), 3));
+            assert_eq!(TrustScore::validator_list(), vec![1, 3]);
+            assert_eq!(count_events(|event| *event == RawEvent::ValidatorEvicted(2)), 1);
+            assert_eq!(count_events(|event| matches!(event, RawEvent::ValidatorRemovedWithStats(2, ..))), 1);
+        });
+}
+
+#[test]
+fn season_reset_carries_scores_over_and_snapshots() {
+    ExtBuilder::default()
+        .with(|| SeasonLength::set(&10))
+        .validators(vec![2, 1])
+        .build()
+        .execute_with(|| {
+            set_score(1, 0.9);
+            set_score(2, 0.3);
+            assert_ok!(TrustScore::set_validator_protection(Origin::root(), 2, true));
+
+            run_to_block(10);
+            assert!(approx(score(1), 0.5 + (0.9 - 0.5) * 0.5));
+            assert_eq!(data(1).raw_score, score(1));
+            assert_eq!(score(2), 0.3);
+            assert_eq!(TrustScore::season_snapshots(0), vec![(1, bps(0.9)), (2, bps(0.3))]);
+            assert_eq!(TrustScore::current_season(), 1);
+            assert_eq!(trust_events().last(), Some(&RawEvent::SeasonReset(0, 1)));
+        });
+}
+
+#[test]
+fn milestones_grant_a_bonus_and_failures_reset_the_streak() {
+    ExtBuilder::default()
+        .with(|| MilestoneInterval::set(&3))
+        .validators(vec![1])
+        .build()
+        .execute_with(|| {
+            report(1, true);
+            report(1, true);
+            let before = score(1);
+            report(1, true);
+            assert!(score(1) - before > MilestoneBonus::get());
+            assert_eq!(count_events(|event| *event == RawEvent::MilestoneReached(1, 3)), 1);
+
+            report(1, false);
+            assert_eq!(data(1).success_streak, 0);
+        });
+}
+
+#[test]
+fn trust_data_batch_preserves_request_order() {
+    ExtBuilder::default().validators(vec![1, 2]).build().execute_with(|| {
+        assert_eq!(TrustScore::trust_data_batch(vec![2, 7, 1]), vec![Some(data(2)), None, Some(data(1))]);
+    });
+}
+
+#[test]
+fn stake_weighting_changes_the_tally() {
+    let tally = |weighting: VoteWeighting| {
+        ExtBuilder::default()
+            .with(move || Weighting::set(&weighting))
+            .validators(vec![1, 2])
+            .build()
+            .execute_with(|| TrustScore::weighted_vote_tally(&[(1, true), (2, false)]))
+    };
+    let per_validator = 5_000 * TierVoteWeights::default().trusted as u128;
+    assert_eq!(tally(VoteWeighting::PureTrust), (per_validator, per_validator));
+    // Stake equals the account id in the mock
+    assert_eq!(tally(VoteWeighting::TrustTimesStake), (per_validator, 2 * per_validator));
+}
+
+#[test]
+fn bootstrap_oracle_seeds_clamped_starting_scores() {
+    ExtBuilder::default().build().execute_with(|| {
+        for who in [BOOTSTRAPPED, OVERSCORED, 5].iter() {
+            assert_ok!(TrustScore::initialize_validator(Origin::root(), *who));
+        }
+        assert_eq!(score(BOOTSTRAPPED), 0.8);
+        assert_eq!(score(OVERSCORED), 1.0);
+        assert_eq!(score(5), InitialTrustScore::get());
+    });
+}
+
+#[test]
+fn quorum_boundary() {
+    ExtBuilder::default().validators(vec![1, 2, 3, 4]).build().execute_with(|| {
+        let votes = vec![(1, true), (2, true), (3, false), (4, false)];
+        assert!(TrustScore::reaches_quorum(votes.clone(), Permill::from_percent(49)));
+        assert!(TrustScore::reaches_quorum(votes.clone(), Permill::from_percent(50)));
+        assert!(!TrustScore::reaches_quorum(votes.clone(), Permill::from_parts(500_001)));
+
+        // Below-threshold validators drop out of the participating weight
+        set_score(4, 0.2);
+        assert!(TrustScore::reaches_quorum(votes, Permill::from_parts(666_666)));
+
+        // No participating weight never reaches quorum
+        assert!(!TrustScore::reaches_quorum(vec![(7, true)], Permill::zero()));
+    });
+}
+
+#[test]
+fn below_average_validators_are_penalized_harder() {
+    ExtBuilder::default()
+        .with(|| BelowAverageEscalation::set(&true))
+        .validators(vec![1, 2])
+        .build()
+        .execute_with(|| {
+            AverageTrustScore::put(0.5);
+            set_score(1, 0.45);
+            set_score(2, 0.55);
+
+            report(1, false);
+            report(2, false);
+            assert!(approx(0.45 - score(1), decrease_fn(0.45) * BelowAverageMultiplier::get()));
+            assert!(approx(0.55 - score(2), decrease_fn(0.55)));
+        });
+}
+
+#[test]
+fn half_life_halves_then_quarters_an_idle_score() {
+    assert_eq!(half_life_decay(8_000, 100, 100), 4_000);
+    assert_eq!(half_life_decay(8_000, 200, 100), 2_000);
+    assert_eq!(half_life_decay(8_000, 50, 100), 6_000);
+    assert_eq!(half_life_decay(8_000, 100, 0), 8_000);
+
+    ExtBuilder::default()
+        .with(|| HalfLife::set(&100))
+        .validators(vec![1])
+        .build()
+        .execute_with(|| {
+            set_score(1, 0.8);
+            run_to_block(101);
+            assert_ok!(TrustScore::apply_decay(Origin::signed(9), 1));
+            assert!(approx(score(1), 0.4));
+
+            run_to_block(201);
+            assert_ok!(TrustScore::apply_decay(Origin::signed(9), 1));
+            assert!(approx(score(1), 0.2));
+        });
+}
+
+#[test]
+fn probation_is_served_with_an_unbroken_streak() {
+    ExtBuilder::default().validators(vec![1, 2]).build().execute_with(|| {
+        // One failure from just above the curve's pole lands between removal and probation
+        for who in [1, 2].iter() {
+            set_score(*who, 0.402);
+            report(*who, false);
+            assert_eq!(data(*who).tier, ValidatorTier::Probation);
+            assert!(!data(*who).flagged_for_removal);
+        }
+        assert_eq!(count_events(|event| *event == RawEvent::TierChanged(1, ValidatorTier::Probation)), 1);
+
+        for _ in 0..PromotionThreshold::get() {
+            report(1, true);
+        }
+        assert_eq!(data(1).tier, ValidatorTier::Trusted);
+        assert_eq!(trust_events().iter().rev().nth(2), Some(&RawEvent::TierChanged(1, ValidatorTier::Trusted)));
+
+        // A failure part-way restarts the count
+        report(2, true);
+        report(2, true);
+        report(2, false);
+        report(2, true);
+        report(2, true);
+        assert_eq!(data(2).tier, ValidatorTier::Probation);
+    });
+}
+
+#[test]
+fn events_use_the_configured_precision_while_storage_keeps_full_precision() {
+    ExtBuilder::default()
+        .with(|| EventScorePrecision::set(&100))
+        .validators(vec![1])
+        .build()
+        .execute_with(|| {
+            report(1, true);
+            let stored = score(1);
+            assert!(stored > 0.5 && stored < 0.51);
+            assert_eq!(trust_events().last(), Some(&RawEvent::TrustScoreUpdated(1, 50, None)));
+        });
+}
+
+#[test]
+fn audit_export_spans_validators_sorted_by_block() {
+    ExtBuilder::default().validators(vec![1, 2]).build().execute_with(|| {
+        report(1, true);
+        run_to_block(2);
+        report(2, false);
+        run_to_block(3);
+        report(1, true);
+
+        let changes = TrustScore::trust_changes_between(2, 3, 0);
+        assert_eq!(changes, vec![(2, 2, bps(score(2))), (1, 3, bps(score(1)))]);
+        assert_eq!(TrustScore::trust_changes_between(1, 3, 0).len(), 3);
+        assert!(TrustScore::trust_changes_between(1, 3, 1).is_empty());
+    });
+}
+
+#[test]
+fn trust_history_keeps_the_latest_entries() {
+    ExtBuilder::default().validators(vec![1]).build().execute_with(|| {
+        for block in 1..=MaxHistoryLength::get() as u64 + 2 {
+            run_to_block(block);
+            report(1, true);
+        }
+        let history = TrustScore::trust_history(1);
+        assert_eq!(history.len(), MaxHistoryLength::get() as usize);
+        assert_eq!(history.first().map(|(block, _)| *block), Some(3));
+        assert_eq!(history.last(), Some(&(MaxHistoryLength::get() as u64 + 2, bps(score(1)))));
+    });
+}
+
+#[test]
+fn scores_are_frozen_while_paused_and_adjust_again_after() {
+    ExtBuilder::default().validators(vec![1]).build().execute_with(|| {
+        assert_noop!(TrustScore::set_trust_adjustments_paused(Origin::signed(1), true), BadOrigin);
+        assert_ok!(TrustScore::set_trust_adjustments_paused(Origin::root(), true));
+        report(1, false);
+        report(1, true);
+        assert_eq!(score(1), 0.5);
+        assert_eq!(trust_events().last(), Some(&RawEvent::ValidationSuccessful(1, 5_000)));
+        assert_noop!(TrustScore::apply_decay(Origin::signed(9), 1), Error::<Test>::AdjustmentsPaused);
+
+        assert_ok!(TrustScore::set_trust_adjustments_paused(Origin::root(), false));
+        report(1, true);
+        assert!(score(1) > 0.5);
+    });
+}
+
+#[test]
+fn paused_time_does_not_count_towards_decay() {
+    ExtBuilder::default()
+        .with(|| HalfLife::set(&100))
+        .validators(vec![1])
+        .build()
+        .execute_with(|| {
+            set_score(1, 0.8);
+            run_to_block(51);
+            assert_ok!(TrustScore::set_trust_adjustments_paused(Origin::root(), true));
+            run_to_block(251);
+            assert_ok!(TrustScore::set_trust_adjustments_paused(Origin::root(), false));
+
+            // Only the 50 blocks before the pause decay
+            assert_ok!(TrustScore::apply_decay(Origin::signed(9), 1));
+            assert!(approx(score(1), 0.6));
+        });
+}
+
+#[test]
+fn median_resists_skew_that_drags_the_mean() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(TrustScore::median_trust_score(), None);
+    });
+    ExtBuilder::default().validators(vec![1, 2, 3, 4]).build().execute_with(|| {
+        for (who, trust_score) in [(1, 0.9), (2, 0.8), (3, 0.7), (4, 0.01)].iter() {
+            set_score(*who, *trust_score);
+        }
+        StatsDirty::put(true);
+        TrustScore::on_idle(1, u64::MAX);
+
+        let (mean, median) = TrustScore::trust_score_summary();
+        assert!(approx(TrustScore::median_trust_score().unwrap(), 0.75));
+        assert!(median.unwrap() > mean + 1_000);
+
+        assert_ok!(TrustScore::initialize_validator(Origin::root(), 5));
+        set_score(5, 0.6);
+        assert!(approx(TrustScore::median_trust_score().unwrap(), 0.7));
+    });
+}
+
+#[test]
+fn silent_validators_auto_exit_while_active_and_protected_ones_survive() {
+    ExtBuilder::default()
+        .with(|| AutoExitPeriod::set(&10))
+        .validators(vec![1, 2, 3])
+        .build()
+        .execute_with(|| {
+            assert_ok!(TrustScore::set_validator_protection(Origin::root(), 3, true));
+            run_to_block(15);
+            assert_ok!(TrustScore::heartbeat(Origin::signed(2)));
+            run_to_block(20);
+
+            assert!(data(1).flagged_for_removal);
+            assert_eq!(data(1).removal_reason, Some(RemovalReason::Inactive));
+            assert_eq!(count_events(|event| *event == RawEvent::ValidatorAutoExited(1)), 1);
+            assert!(!data(2).flagged_for_removal);
+            assert!(!data(3).flagged_for_removal);
+
+            // A flagged validator can no longer signal liveness
+            assert_noop!(TrustScore::heartbeat(Origin::signed(1)), Error::<Test>::ValidatorNotFound);
+        });
+}
+
+#[test]
+fn upheld_dispute_returns_the_bond() {
+    ExtBuilder::default().validators(vec![1]).build().execute_with(|| {
+        assert_noop!(TrustScore::file_dispute(Origin::signed(1)), Error::<Test>::NothingToDispute);
+        report(1, false);
+
+        assert_ok!(TrustScore::file_dispute(Origin::signed(1)));
+        assert_eq!(TrustScore::disputes(1), Some((1, DisputeBond::get())));
+        assert_eq!(Balances::reserved_balance(1), DisputeBond::get());
+        assert_noop!(TrustScore::file_dispute(Origin::signed(1)), Error::<Test>::DisputeAlreadyOpen);
+
+        assert_ok!(TrustScore::resolve_dispute(Origin::root(), 1, true));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 1_000);
+        assert_eq!(trust_events().last(), Some(&RawEvent::DisputeResolved(1, true, DisputeBond::get())));
+    });
+}
+
+#[test]
+fn rejected_dispute_slashes_the_bond() {
+    ExtBuilder::default().validators(vec![1]).build().execute_with(|| {
+        report(1, false);
+        assert_ok!(TrustScore::file_dispute(Origin::signed(1)));
+        assert_noop!(TrustScore::resolve_dispute(Origin::signed(1), 1, true), BadOrigin);
+
+        assert_ok!(TrustScore::resolve_dispute(Origin::root(), 1, false));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 1_000 - DisputeBond::get());
+        assert_noop!(TrustScore::resolve_dispute(Origin::root(), 1, false), Error::<Test>::DisputeNotFound);
+    });
+}
+
+#[test]
+fn dispute_needs_a_bond() {
+    ExtBuilder::default().validators(vec![11]).build().execute_with(|| {
+        report(11, false);
+        assert_noop!(TrustScore::file_dispute(Origin::signed(11)), Error::<Test>::InsufficientBond);
+    });
+}
+
+#[test]
+fn health_score_components_move_independently() {
+    ExtBuilder::default().validators(vec![1]).build().execute_with(|| {
+        // Trust 5_000, no track record (5_000), just seen (10_000)
+        assert_eq!(TrustScore::health_score(&1), Some(20_000 / 3));
+
+        set_score(1, 0.8);
+        assert_eq!(TrustScore::health_score(&1), Some(23_000 / 3));
+
+        RecentOutcomes::<Test>::insert(1, vec![true; 4]);
+        assert_eq!(TrustScore::health_score(&1), Some(28_000 / 3));
+
+        run_to_block(1 + HealthRecencyPeriod::get() / 2);
+        assert_eq!(TrustScore::health_score(&1), Some(23_000 / 3));
+
+        assert_eq!(TrustScore::health_score(&7), None);
+    });
+}
+
+#[test]
+fn success_rate_covers_only_the_recent_window() {
+    ExtBuilder::default().validators(vec![1]).build().execute_with(|| {
+        assert_eq!(TrustScore::recent_success_rate(&1), None);
+        report(1, false);
+        for _ in 0..HealthWindow::get() {
+            report(1, true);
+        }
+        assert_eq!(TrustScore::recent_outcomes(1).len(), HealthWindow::get() as usize);
+        assert_eq!(TrustScore::recent_success_rate(&1), Some(10_000));
+    });
+}
+
+#[test]
+fn score_deltas_reconcile_with_stored_scores() {
+    ExtBuilder::default()
+        .with(|| EmitScoreDelta::set(&true))
+        .validators(vec![1])
+        .build()
+        .execute_with(|| {
+            for matched in [true, true, false, true, false].iter() {
+                let before = bps(score(1));
+                report(1, *matched);
+                let after = bps(score(1));
+                assert_eq!(
+                    trust_events().last(),
+                    Some(&RawEvent::TrustScoreUpdated(1, after, Some(after as i32 - before as i32)))
+                );
+            }
+        });
+}
+
+#[test]
+fn score_deltas_are_left_out_unless_enabled() {
+    ExtBuilder::default().validators(vec![1]).build().execute_with(|| {
+        report(1, true);
+        assert_eq!(trust_events().last(), Some(&RawEvent::TrustScoreUpdated(1, bps(score(1)), None)));
+    });
+}
+
+#[test]
+fn leaderboard_leaves_out_low_and_flagged_validators() {
+    ExtBuilder::default().validators(vec![1, 2, 3]).build().execute_with(|| {
+        set_score(1, 0.8);
+        set_score(2, 0.2);
+        set_flagged(3);
+
+        assert_eq!(TrustScore::get_validators_by_trust(), vec![(1, bps(0.8))]);
+        assert_eq!(TrustScore::all_validators_by_trust(), vec![(1, bps(0.8)), (3, bps(0.5)), (2, bps(0.2))]);
+    });
+}
+
+#[test]
+fn digest_carries_the_top_validators() {
+    ExtBuilder::default()
+        .with(|| DigestTopN::set(&2))
+        .validators(vec![1, 2, 3])
+        .build()
+        .execute_with(|| {
+            set_score(1, 0.6);
+            set_score(2, 0.9);
+            set_score(3, 0.7);
+            StatsDirty::put(true);
+            TrustScore::on_idle(1, u64::MAX);
+            TrustScore::on_finalize(1);
+
+            let top = System::digest()
+                .logs
+                .iter()
+                .find_map(|item| match item {
+                    DigestItem::Consensus(id, data) if *id == TRUST_ENGINE_ID => Some(data.clone()),
+                    _ => None,
+                })
+                .expect("trust digest present");
+            let decoded: Vec<(u64, u32)> = Decode::decode(&mut &top[..]).unwrap();
+            assert_eq!(decoded, vec![(2, bps(0.9)), (3, bps(0.7))]);
+        });
+}
+
+#[test]
+fn digest_is_off_by_default() {
+    ExtBuilder::default().validators(vec![1]).build().execute_with(|| {
+        TrustScore::on_finalize(1);
+        assert!(System::digest().logs.is_empty());
+    });
+}
+
+#[test]
+fn slash_scales_inversely_with_trust_up_to_the_cap() {
+    ExtBuilder::default().validators(vec![1, 2, 3, 4]).build().execute_with(|| {
+        set_score(2, 0.25);
+        set_score(3, 0.1);
+        set_score(4, 1.0);
+
+        assert_eq!(TrustScore::slash_amount(&1, 100), 200);
+        assert_eq!(TrustScore::slash_amount(&2, 100), 400);
+        assert_eq!(TrustScore::slash_amount(&3, 100), 100 * MaxSlashMultiple::get() as u64);
+        assert_eq!(TrustScore::slash_amount(&4, 100), 100);
+        assert_eq!(TrustScore::slash_amount(&7, 100), 100 * MaxSlashMultiple::get() as u64);
+    });
+}
+
+#[test]
+fn established_validator_resists_a_one_off_drop_but_still_erodes() {
+    ExtBuilder::default()
+        .with(|| EstablishedValidations::set(&5))
+        .validators(vec![1, 2])
+        .build()
+        .execute_with(|| {
+            TrustScores::<Test>::mutate(1, |data| data.as_mut().unwrap().successful_validations = 10);
+            set_score(1, 0.6);
+            set_score(2, 0.6);
+
+            // Above the floor, failures cost an established validator the normal penalty
+            let mut previous = score(1);
+            for _ in 0..3 {
+                report(1, false);
+                report(2, false);
+                assert!(score(1) < previous);
+                assert_eq!(score(1), score(2));
+                previous = score(1);
+            }
+
+            // A step that would cross the floor stops at it
+            set_score(1, 0.4005);
+            set_score(2, 0.4005);
+            report(1, false);
+            report(2, false);
+            assert_eq!(score(1), EstablishedFloor::get());
+            assert!(data(2).flagged_for_removal);
+        });
+}
+
+#[test]
+fn failures_do_not_revoke_established_standing() {
+    ExtBuilder::default()
+        .with(|| EstablishedValidations::set(&5))
+        .validators(vec![1, 2])
+        .build()
+        .execute_with(|| {
+            // More failures than successes, but past the success count all the same
+            TrustScores::<Test>::mutate(1, |data| {
+                let data = data.as_mut().unwrap();
+                data.successful_validations = 6;
+                data.failed_validations = 20;
+            });
+            TrustScores::<Test>::mutate(2, |data| data.as_mut().unwrap().successful_validations = 5);
+            set_score(1, 0.4005);
+            set_score(2, 0.4005);
+
+            report(1, false);
+            report(2, false);
+            assert_eq!(score(1), EstablishedFloor::get());
+            assert!(data(2).flagged_for_removal);
+        });
+}
+
+#[test]
+fn tier_weights_reshape_the_tally() {
+    ExtBuilder::default().validators(vec![1, 2]).build().execute_with(|| {
+        TrustScores::<Test>::mutate(2, |data| data.as_mut().unwrap().tier = ValidatorTier::Probation);
+        assert_eq!(TrustScore::weighted_vote_tally(&[(1, true), (2, false)]), (15_000, 5_000));
+
+        let weights = TierVoteWeights { trusted: 5, probation: 5, flagged: 0 };
+        assert_ok!(TrustScore::set_tier_weights(Origin::root(), weights));
+        assert_eq!(TrustScore::weighted_vote_tally(&[(1, true), (2, false)]), (25_000, 25_000));
+        assert_eq!(trust_events().last(), Some(&RawEvent::TierWeightsSet(weights)));
+
+        let inverted = TierVoteWeights { trusted: 1, probation: 2, flagged: 0 };
+        assert_noop!(TrustScore::set_tier_weights(Origin::root(), inverted), Error::<Test>::NonMonotonicTierWeights);
+
+        // Flagged validators carry the flagged weight whatever their tier
+        set_flagged(1);
+        assert_eq!(TrustScore::weighted_vote_tally(&[(1, true)]), (0, 0));
+    });
+}
+
+#[test]
+fn counters_reset_without_touching_the_score() {
+    ExtBuilder::default().validators(vec![1]).build().execute_with(|| {
+        report(1, true);
+        report(1, true);
+        report(1, false);
+        let trust_score = score(1);
+
+        assert_noop!(TrustScore::reset_validator_counters(Origin::signed(1), 1), BadOrigin);
+        assert_ok!(TrustScore::reset_validator_counters(Origin::root(), 1));
+        let reset = data(1);
+        assert_eq!((reset.successful_validations, reset.failed_validations, reset.success_streak), (0, 0, 0));
+        assert_eq!(TrustScore::failure_streak(1), 0);
+        assert_eq!(reset.trust_score, trust_score);
+        assert_eq!(trust_events().last(), Some(&RawEvent::ValidatorCountersReset(1, 2, 1)));
+
+        assert_noop!(TrustScore::reset_validator_counters(Origin::root(), 7), Error::<Test>::ValidatorNotFound);
+    });
+}
+
+#[test]
+fn queued_validator_activates_after_the_delay() {
+    ExtBuilder::default()
+        .with(|| OnboardingDelay::set(&5))
+        .build()
+        .execute_with(|| {
+            assert_ok!(TrustScore::initialize_validator(Origin::root(), 1));
+            assert!(TrustScore::trust_scores(1).is_none());
+            assert!(!TrustScore::can_validate(&1));
+            assert_eq!(trust_events().last(), Some(&RawEvent::ValidatorQueued(1, 6)));
+            assert_noop!(TrustScore::initialize_validator(Origin::root(), 1), Error::<Test>::ValidatorPending);
+
+            run_to_block(5);
+            assert!(!TrustScore::can_validate(&1));
+            run_to_block(6);
+            assert!(TrustScore::can_validate(&1));
+            assert_eq!(TrustScore::validator_list(), vec![1]);
+            assert_eq!(TrustScore::pending_validators(1), None);
+        });
+}
+
+#[test]
+fn activations_are_indexed_by_their_block() {
+    ExtBuilder::default()
+        .with(|| OnboardingDelay::set(&5))
+        .build()
+        .execute_with(|| {
+            assert_ok!(TrustScore::initialize_validator(Origin::root(), 1));
+            run_to_block(3);
+            assert_ok!(TrustScore::initialize_validator(Origin::root(), 2));
+            assert_eq!(TrustScore::activations_due(6), vec![1]);
+            assert_eq!(TrustScore::activations_due(8), vec![2]);
+
+            // A block with nothing due reads only its own empty entry
+            assert_eq!(TrustScore::activate_pending(7), (1, 0));
+
+            run_to_block(6);
+            assert_eq!(TrustScore::validator_list(), vec![1]);
+            assert!(TrustScore::activations_due(6).is_empty());
+            run_to_block(8);
+            assert_eq!(TrustScore::validator_list(), vec![1, 2]);
+            assert_eq!(TrustScore::pending_validators(2), None);
+        });
+}
+
+#[test]
+fn queued_validators_hold_a_slot_in_a_full_set() {
+    ExtBuilder::default()
+        .with(|| {
+            OnboardingDelay::set(&5);
+            MaxValidators::set(&1);
+        })
+        .build()
+        .execute_with(|| {
+            assert_ok!(TrustScore::initialize_validator(Origin::root(), 1));
+            assert_noop!(TrustScore::initialize_validator(Origin::root(), 2), Error::<Test>::TooManyValidators);
+        });
+}
+
+#[test]
+fn low_trust_senders_are_kept_out_of_gated_calls() {
+    ExtBuilder::default().validators(vec![1, 2]).build().execute_with(|| {
+        set_score(2, 0.2);
+        let gated = crate::mock::Call::TrustScore(crate::Call::heartbeat());
+        let open = crate::mock::Call::TrustScore(crate::Call::file_dispute());
+        let info = DispatchInfo::default();
+        let check = CheckValidatorTrust::<Test>::new();
+
+        assert!(check.validate(&1, &gated, &info, 0).is_ok());
+        assert_eq!(check.validate(&2, &gated, &info, 0), InvalidTransaction::Custom(LOW_TRUST_TRANSACTION).into());
+        assert!(check.validate(&2, &open, &info, 0).is_ok());
+        // Accounts outside the trust system cannot send gated calls either
+        assert!(check.validate(&7, &gated, &info, 0).is_err());
+    });
+}
+
+#[test]
+fn trust_export_goes_to_the_sender_with_the_expected_fields() {
+    ExtBuilder::default().validators(vec![1, 2]).build().execute_with(|| {
+        report(1, true);
+        assert_ok!(TrustScore::export_trust_xcm(Origin::signed(1), 1, 5));
+
+        let sent = SENT_EXPORTS.with(|sent| sent.borrow().clone());
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].0, 5);
+        let payload: TrustExport<u64, u64> = Decode::decode(&mut &sent[0].1[..]).unwrap();
+        assert_eq!(
+            payload,
+            TrustExport::V1 {
+                validator: 1,
+                trust_score: bps(score(1)),
+                successful_validations: 1,
+                failed_validations: 0,
+                tier: ValidatorTier::Trusted,
+                flagged_for_removal: false,
+                last_updated: 1,
+            }
+        );
+        assert_eq!(trust_events().last(), Some(&RawEvent::TrustExported(1)));
+    });
+}
+
+#[test]
+fn trust_export_is_restricted_and_rate_limited() {
+    ExtBuilder::default().validators(vec![1, 2]).build().execute_with(|| {
+        assert_noop!(TrustScore::export_trust_xcm(Origin::signed(2), 1, 5), BadOrigin);
+        assert_noop!(TrustScore::export_trust_xcm(Origin::root(), 1, 0), Error::<Test>::ExportFailed);
+
+        assert_ok!(TrustScore::export_trust_xcm(Origin::root(), 1, 5));
+        assert_noop!(TrustScore::export_trust_xcm(Origin::signed(1), 1, 5), Error::<Test>::ExportRateLimited);
+        // The limit is per validator
+        assert_ok!(TrustScore::export_trust_xcm(Origin::signed(2), 2, 5));
+
+        run_to_block(1 + ExportCooldown::get());
+        assert_ok!(TrustScore::export_trust_xcm(Origin::signed(1), 1, 5));
+    });
+}
+
+#[test]
+fn trust_admin_handover_takes_two_steps() {
+    ExtBuilder::default().build().execute_with(|| {
+        let weights = TierVoteWeights::default();
+        assert_noop!(TrustScore::set_tier_weights(Origin::signed(5), weights), BadOrigin);
+        assert_noop!(TrustScore::propose_new_trust_admin(Origin::signed(5), 5), BadOrigin);
+
+        assert_ok!(TrustScore::propose_new_trust_admin(Origin::root(), 5));
+        assert_eq!(trust_events().last(), Some(&RawEvent::TrustAdminProposed(5)));
+        // Proposing alone grants nothing
+        assert_noop!(TrustScore::set_tier_weights(Origin::signed(5), weights), BadOrigin);
+        assert_noop!(TrustScore::accept_trust_admin(Origin::signed(6)), Error::<Test>::NotPendingAdmin);
+
+        assert_ok!(TrustScore::accept_trust_admin(Origin::signed(5)));
+        assert_eq!(TrustScore::trust_admin(), Some(5));
+        assert_eq!(TrustScore::pending_trust_admin(), None);
+        assert_ok!(TrustScore::set_tier_weights(Origin::signed(5), weights));
+    });
+}
+
+#[test]
+fn pending_trust_admin_can_be_cancelled() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(TrustScore::cancel_pending_admin(Origin::root()), Error::<Test>::NoPendingAdmin);
+        assert_ok!(TrustScore::propose_new_trust_admin(Origin::root(), 7));
+        assert_ok!(TrustScore::cancel_pending_admin(Origin::root()));
+        assert_eq!(trust_events().last(), Some(&RawEvent::PendingTrustAdminCancelled(7)));
+
+        assert_noop!(TrustScore::accept_trust_admin(Origin::signed(7)), Error::<Test>::NoPendingAdmin);
+    });
+}
+
+#[test]
+fn failures_suspend_until_the_timeout_elapses() {
+    ExtBuilder::default()
+        .with(|| SuspendOnFailure::set(&true))
+        .validators(vec![1])
+        .build()
+        .execute_with(|| {
+            report(1, false);
+            assert_eq!(TrustScore::suspended_until(1), Some(1 + SuspensionLength::get()));
+            assert!(!TrustScore::can_validate(&1));
+
+            run_to_block(SuspensionLength::get());
+            assert!(!TrustScore::can_validate(&1));
+            run_to_block(1 + SuspensionLength::get());
+            assert!(TrustScore::can_validate(&1));
+            assert_eq!(TrustScore::suspended_until(1), None);
+            assert_eq!(trust_events().last(), Some(&RawEvent::SuspensionLifted(1)));
+
+            // Consecutive failures lengthen the suspension up to the maximum
+            let now = System::block_number();
+            report(1, false);
+            assert_eq!(TrustScore::suspended_until(1), Some(now + 2 * SuspensionLength::get()));
+            report(1, false);
+            assert_eq!(TrustScore::suspended_until(1), Some(now + MaxSuspensionLength::get()));
+        });
+}
+
+#[test]
+fn suspensions_are_lifted_from_the_index_for_their_block() {
+    ExtBuilder::default()
+        .with(|| SuspendOnFailure::set(&true))
+        .validators(vec![1])
+        .build()
+        .execute_with(|| {
+            let lifted = || count_events(|event| matches!(event, RawEvent::SuspensionLifted(..)));
+            report(1, false);
+            assert_eq!(TrustScore::suspensions_ending(6), vec![1]);
+
+            // A longer suspension supersedes the entry indexed at the earlier block
+            run_to_block(3);
+            report(1, false);
+            assert_eq!(TrustScore::suspended_until(1), Some(13));
+            run_to_block(7);
+            assert!(!TrustScore::can_validate(&1));
+            assert!(TrustScore::suspensions_ending(6).is_empty());
+            assert_eq!(lifted(), 0);
+
+            // A block with nothing due reads only its own empty entry
+            assert_eq!(TrustScore::lift_suspensions(9).0, 1);
+
+            run_to_block(13);
+            assert!(TrustScore::can_validate(&1));
+            assert_eq!(lifted(), 1);
+        });
+}
+
+#[test]
+fn failures_only_count_the_streak_when_suspension_is_off() {
+    ExtBuilder::default().validators(vec![1]).build().execute_with(|| {
+        report(1, false);
+        report(1, false);
+        assert_eq!(TrustScore::failure_streak(1), 2);
+        assert_eq!(TrustScore::suspended_until(1), None);
+
+        report(1, true);
+        assert_eq!(TrustScore::failure_streak(1), 0);
+    });
+}
+
+#[test]
+fn rewards_split_by_trust_and_sum_to_the_pot() {
+    ExtBuilder::default().validators(vec![1, 2, 3]).build().execute_with(|| {
+        set_score(1, 0.6);
+        set_score(2, 0.4);
+        // Cannot validate, so earns nothing
+        set_score(3, 0.2);
+
+        let shares = TrustScore::reward_shares(1_001);
+        assert_eq!(shares, vec![(1, 601), (2, 400)]);
+        assert_eq!(shares.iter().map(|(_, share)| share).sum::<u64>(), 1_001);
+
+        assert_ok!(TrustScore::distribute_rewards(Origin::root(), 1_001));
+        assert_eq!(Balances::free_balance(1), 1_601);
+        assert_eq!(Balances::free_balance(2), 1_400);
+        assert_eq!(Balances::free_balance(3), 1_000);
+        assert_eq!(Balances::free_balance(REWARD_SOURCE), 1_000_000 - 1_001);
+    });
+}
+
+#[test]
+fn a_failed_reward_transfer_pays_no_one() {
+    ExtBuilder::default().validators(vec![1, 2, 3]).build().execute_with(|| {
+        // The source covers the first two equal shares but not the third
+        assert_noop!(
+            TrustScore::distribute_rewards(Origin::root(), 1_500_000),
+            Error::<Test>::RewardTransferFailed
+        );
+        assert_eq!((Balances::free_balance(1), Balances::free_balance(2)), (1_000, 1_000));
+        assert_eq!(Balances::free_balance(REWARD_SOURCE), 1_000_000);
+        assert_eq!(count_events(|event| matches!(event, RawEvent::RewardDistributed(..))), 0);
+    });
+}
+
+#[test]
+fn rewards_need_an_eligible_validator() {
+    ExtBuilder::default().validators(vec![1]).build().execute_with(|| {
+        set_score(1, 0.2);
+        assert_noop!(TrustScore::distribute_rewards(Origin::root(), 100), Error::<Test>::NoEligibleValidators);
+        assert_noop!(TrustScore::distribute_rewards(Origin::signed(1), 100), BadOrigin);
+    });
+}
+
+#[test]
+fn canonical_order_ignores_insertion_order() {
+    let canonical = |order: Vec<u64>| {
+        ExtBuilder::default().validators(order).build().execute_with(TrustScore::validators_canonical)
+    };
+    assert_eq!(canonical(vec![3, 1, 2]), vec![1, 2, 3]);
+    assert_eq!(canonical(vec![2, 3, 1]), vec![1, 2, 3]);
+}
+
+#[test]
+fn equal_scores_rank_in_canonical_order() {
+    ExtBuilder::default().validators(vec![3, 1, 2]).build().execute_with(|| {
+        set_score(2, 0.8);
+        assert_eq!(TrustScore::all_validators_by_trust(), vec![(2, bps(0.8)), (1, bps(0.5)), (3, bps(0.5))]);
+    });
+}
+
+#[test]
+fn batch_matches_the_same_votes_reported_one_by_one() {
+    let batched = ExtBuilder::default().validators(vec![1]).build().execute_with(|| {
+        assert_ok!(batch(1, 8, 10));
+        assert_eq!((data(1).successful_validations, data(1).failed_validations), (8, 2));
+        assert_eq!(count_events(|event| *event == RawEvent::BatchValidationReported(1, 8, 10)), 1);
+        score(1)
+    });
+    let individual = ExtBuilder::default().validators(vec![1]).build().execute_with(|| {
+        for i in 0..10u64 {
+            report(1, (i + 1) * 8 / 10 > i * 8 / 10);
+        }
+        score(1)
+    });
+    assert!(approx(batched, individual));
+}
+
+#[test]
+fn oversized_or_inconsistent_batches_are_rejected() {
+    ExtBuilder::default().validators(vec![1]).build().execute_with(|| {
+        assert_noop!(batch(1, 0, 0), Error::<Test>::InvalidBatch);
+        assert_noop!(batch(1, 3, 2), Error::<Test>::InvalidBatch);
+        assert_noop!(batch(1, 1, MaxBatchSize::get() + 1), Error::<Test>::InvalidBatch);
+    });
+}
+
+#[test]
+fn batch_counts_each_validation_against_the_throttle() {
+    ExtBuilder::default()
+        .with(|| MaxUpdatesPerReporterPerBlock::set(&3))
+        .validators(vec![1])
+        .build()
+        .execute_with(|| {
+            assert_noop!(batch(1, 2, 4), Error::<Test>::ReporterRateLimited);
+            assert_ok!(batch(1, 2, 3));
+            assert_noop!(report_as(9, 1, true), Error::<Test>::ReporterRateLimited);
+        });
+}
+
+#[test]
+fn summary_matches_the_individual_getters() {
+    ExtBuilder::default().validators(vec![1, 2]).build().execute_with(|| {
+        report(1, true);
+        report(1, true);
+        report(1, false);
+
+        let summary = TrustScore::validator_summary(&1).expect("known validator");
+        let trust_data = data(1);
+        assert_eq!(summary.trust_score, bps(trust_data.trust_score));
+        assert_eq!(summary.tier, trust_data.tier);
+        assert_eq!(summary.rank, Some(1));
+        assert_eq!(summary.recent_success_rate, Some(6_666));
+        assert_eq!(summary.success_streak, 0);
+        assert_eq!(summary.failure_streak, 1);
+        assert_eq!((summary.successful_validations, summary.failed_validations), (2, 1));
+        assert!(!summary.flagged_for_removal && !summary.protected);
+        assert_eq!(summary.removal_reason, None);
+        assert_eq!(summary.suspended_until, None);
+        assert_eq!(summary.last_updated, trust_data.last_updated);
+        assert_eq!(summary.health_score, TrustScore::health_score(&1));
+
+        assert_eq!(TrustScore::validator_summary(&2).and_then(|summary| summary.rank), Some(2));
+        assert_eq!(TrustScore::validator_summary(&7), None);
+    });
+}